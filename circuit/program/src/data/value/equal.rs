@@ -37,3 +37,68 @@ impl<A: Aleo> Equal<Self> for Value<A> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    fn sample_record_value(a: bool) -> Value<Circuit> {
+        let value = console::Value::<<Circuit as Environment>::Network>::from_str(&format!(
+            r"{{
+    owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private,
+    a: {a}.private,
+    b: 123456789field.public,
+    c: 0group.private,
+    d: {{
+        e: true.private,
+        f: 123456789field.private,
+        g: 0group.private
+    }},
+    _nonce: 0group.public
+}}"
+        ))
+        .unwrap();
+        Value::new(Mode::Private, value)
+    }
+
+    #[test]
+    fn test_is_equal_record() {
+        let value = sample_record_value(true);
+        let mismatched_value = sample_record_value(false);
+
+        // Note: The counts match those of the record, as the value defers to the record equality gadget.
+        Circuit::scope("is_equal", || {
+            let candidate = value.is_equal(&value);
+            assert!(candidate.eject_value());
+            assert_scope!(17, 0, 23, 33);
+        });
+
+        Circuit::scope("is_equal_mismatched", || {
+            let candidate = value.is_equal(&mismatched_value);
+            assert!(!candidate.eject_value());
+        });
+
+        Circuit::scope("is_not_equal", || {
+            let candidate = value.is_not_equal(&mismatched_value);
+            assert!(candidate.eject_value());
+        });
+
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_is_equal_variant_mismatch() {
+        let record_value = sample_record_value(true);
+        let plaintext_value = Value::<Circuit>::new(Mode::Private, console::Value::from_str("{ a: true }").unwrap());
+
+        // Ensure values of different variants are unequal, without any constraints.
+        Circuit::scope("is_equal_variant_mismatch", || {
+            let candidate = record_value.is_equal(&plaintext_value);
+            assert!(!candidate.eject_value());
+            assert_scope!(0, 0, 0, 0);
+        });
+
+        Circuit::reset();
+    }
+}
@@ -63,6 +63,25 @@ impl<N: Network> Signature<N> {
         self.verify_bits(address, &message.to_bits_le())
     }
 
+    /// Verifies a batch of signatures, each for the given address and message (as bytes).
+    /// Returns `true` only if every signature in the batch is valid.
+    ///
+    /// Note: The signature scheme does not currently support a batched verification equation,
+    /// so this falls back to verifying each signature individually.
+    pub fn batch_verify_bytes(items: &[(Address<N>, &[u8], Signature<N>)]) -> bool {
+        items.iter().all(|(address, message, signature)| signature.verify_bytes(address, message))
+    }
+
+    /// Returns the address of the signer, if the signature is valid for the given message (as bytes).
+    /// The address is derived from the compute key of the signature, and the signature is verified against it.
+    pub fn recover_signer(&self, message: &[u8]) -> Result<Address<N>> {
+        // Derive the address of the signer.
+        let address = self.to_address();
+        // Ensure the signature is valid for the signer.
+        ensure!(self.verify_bytes(&address, message), "Failed to recover the signer: the signature is invalid");
+        Ok(address)
+    }
+
     /// Verifies a signature for the given address and message (as bits).
     pub fn verify_bits(&self, address: &Address<N>, message: &[bool]) -> bool {
         // Pack the bits into field elements.
@@ -132,6 +151,49 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_batch_verify_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a batch of signed messages.
+        let mut batch = Vec::new();
+        let messages: Vec<Vec<u8>> = (0..4).map(|i| (0..i * 8).map(|_| Uniform::rand(rng)).collect()).collect();
+        for message in &messages {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+            batch.push((address, message.as_slice(), Signature::sign_bytes(&private_key, message, rng)?));
+        }
+
+        // Check that a batch of valid signatures is valid.
+        assert!(Signature::batch_verify_bytes(&batch));
+        assert!(Signature::<CurrentNetwork>::batch_verify_bytes(&[]));
+
+        // Check that a batch with one valid and one invalid signature is invalid.
+        let invalid = (batch[1].0, batch[2].1, batch[1].2);
+        assert!(!Signature::batch_verify_bytes(&[batch[0], invalid]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_recover_signer() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an address and a private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Check that a valid signature recovers the address of the signer.
+        let message: Vec<u8> = (0..32).map(|_| Uniform::rand(rng)).collect();
+        let signature = Signature::sign_bytes(&private_key, &message, rng)?;
+        assert_eq!(signature.recover_signer(&message)?, address);
+
+        // Check that a signature for another message is rejected.
+        let mut failure_message = message.clone();
+        failure_message[0] ^= 1;
+        assert!(signature.recover_signer(&failure_message).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_sign_and_verify_bits() -> Result<()> {
         let rng = &mut TestRng::default();
@@ -35,38 +35,38 @@ use snarkvm_console_algorithms::{
 
 lazy_static! {
     /// The group bases for the Aleo signature and encryption schemes.
-    static ref GENERATOR_G: Vec<Group<CanaryV0 >> = CanaryV0::new_bases("AleoAccountEncryptionAndSignatureScheme0");
+    static ref GENERATOR_G: Vec<Group<CanaryV0 >> = CanaryV0::new_bases(setup_domain::<CanaryV0>("account"));
 
     /// The Varuna sponge parameters.
     static ref VARUNA_FS_PARAMETERS: FiatShamirParameters<CanaryV0> = FiatShamir::<CanaryV0>::sample_parameters();
 
     /// The encryption domain as a constant field element.
-    static ref ENCRYPTION_DOMAIN: Field<CanaryV0> = Field::<CanaryV0>::new_domain_separator("AleoSymmetricEncryption0");
+    static ref ENCRYPTION_DOMAIN: Field<CanaryV0> = Field::<CanaryV0>::new_domain_separator(setup_domain::<CanaryV0>("encryption"));
     /// The graph key domain as a constant field element.
-    static ref GRAPH_KEY_DOMAIN: Field<CanaryV0> = Field::<CanaryV0>::new_domain_separator("AleoGraphKey0");
+    static ref GRAPH_KEY_DOMAIN: Field<CanaryV0> = Field::<CanaryV0>::new_domain_separator(setup_domain::<CanaryV0>("graph_key"));
     /// The serial number domain as a constant field element.
-    static ref SERIAL_NUMBER_DOMAIN: Field<CanaryV0> = Field::<CanaryV0>::new_domain_separator("AleoSerialNumber0");
+    static ref SERIAL_NUMBER_DOMAIN: Field<CanaryV0> = Field::<CanaryV0>::new_domain_separator(setup_domain::<CanaryV0>("serial_number"));
 
     /// The BHP hash function, which can take an input of up to 256 bits.
-    pub static ref CANARY_BHP_256: BHP256<CanaryV0> = BHP256::<CanaryV0>::setup("AleoBHP256").expect("Failed to setup BHP256");
+    pub static ref CANARY_BHP_256: BHP256<CanaryV0> = BHP256::<CanaryV0>::setup(setup_domain::<CanaryV0>("bhp256")).expect("Failed to setup BHP256");
     /// The BHP hash function, which can take an input of up to 512 bits.
-    pub static ref CANARY_BHP_512: BHP512<CanaryV0> = BHP512::<CanaryV0>::setup("AleoBHP512").expect("Failed to setup BHP512");
+    pub static ref CANARY_BHP_512: BHP512<CanaryV0> = BHP512::<CanaryV0>::setup(setup_domain::<CanaryV0>("bhp512")).expect("Failed to setup BHP512");
     /// The BHP hash function, which can take an input of up to 768 bits.
-    pub static ref CANARY_BHP_768: BHP768<CanaryV0> = BHP768::<CanaryV0>::setup("AleoBHP768").expect("Failed to setup BHP768");
+    pub static ref CANARY_BHP_768: BHP768<CanaryV0> = BHP768::<CanaryV0>::setup(setup_domain::<CanaryV0>("bhp768")).expect("Failed to setup BHP768");
     /// The BHP hash function, which can take an input of up to 1024 bits.
-    pub static ref CANARY_BHP_1024: BHP1024<CanaryV0> = BHP1024::<CanaryV0>::setup("AleoBHP1024").expect("Failed to setup BHP1024");
+    pub static ref CANARY_BHP_1024: BHP1024<CanaryV0> = BHP1024::<CanaryV0>::setup(setup_domain::<CanaryV0>("bhp1024")).expect("Failed to setup BHP1024");
 
     /// The Pedersen hash function, which can take an input of up to 64 bits.
-    pub static ref CANARY_PEDERSEN_64: Pedersen64<CanaryV0> = Pedersen64::<CanaryV0>::setup("AleoPedersen64");
+    pub static ref CANARY_PEDERSEN_64: Pedersen64<CanaryV0> = Pedersen64::<CanaryV0>::setup(setup_domain::<CanaryV0>("ped64"));
     /// The Pedersen hash function, which can take an input of up to 128 bits.
-    pub static ref CANARY_PEDERSEN_128: Pedersen128<CanaryV0> = Pedersen128::<CanaryV0>::setup("AleoPedersen128");
+    pub static ref CANARY_PEDERSEN_128: Pedersen128<CanaryV0> = Pedersen128::<CanaryV0>::setup(setup_domain::<CanaryV0>("ped128"));
 
     /// The Poseidon hash function, using a rate of 2.
-    pub static ref CANARY_POSEIDON_2: Poseidon2<CanaryV0> = Poseidon2::<CanaryV0>::setup("AleoPoseidon2").expect("Failed to setup Poseidon2");
+    pub static ref CANARY_POSEIDON_2: Poseidon2<CanaryV0> = Poseidon2::<CanaryV0>::setup(setup_domain::<CanaryV0>("psd2")).expect("Failed to setup Poseidon2");
     /// The Poseidon hash function, using a rate of 4.
-    pub static ref CANARY_POSEIDON_4: Poseidon4<CanaryV0> = Poseidon4::<CanaryV0>::setup("AleoPoseidon4").expect("Failed to setup Poseidon4");
+    pub static ref CANARY_POSEIDON_4: Poseidon4<CanaryV0> = Poseidon4::<CanaryV0>::setup(setup_domain::<CanaryV0>("psd4")).expect("Failed to setup Poseidon4");
     /// The Poseidon hash function, using a rate of 8.
-    pub static ref CANARY_POSEIDON_8: Poseidon8<CanaryV0> = Poseidon8::<CanaryV0>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
+    pub static ref CANARY_POSEIDON_8: Poseidon8<CanaryV0> = Poseidon8::<CanaryV0>::setup(setup_domain::<CanaryV0>("psd8")).expect("Failed to setup Poseidon8");
 
     pub static ref CANARY_CREDITS_PROVING_KEYS: IndexMap<String, Arc<VarunaProvingKey<Console>>> = {
         let mut map = IndexMap::new();
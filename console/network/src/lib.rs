@@ -34,6 +34,11 @@ pub use mainnet_v0::*;
 mod testnet_v0;
 pub use testnet_v0::*;
 
+#[cfg(feature = "test")]
+mod mock_network;
+#[cfg(feature = "test")]
+pub use mock_network::*;
+
 pub mod prelude {
     pub use crate::{environment::prelude::*, Network};
 }
@@ -50,6 +55,7 @@ use snarkvm_console_collections::merkle_tree::{MerklePath, MerkleTree};
 use snarkvm_console_types::{Field, Group, Scalar};
 use snarkvm_curves::PairingEngine;
 
+use core::marker::PhantomData;
 use indexmap::IndexMap;
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
@@ -68,6 +74,23 @@ pub type FiatShamirParameters<N> = <FiatShamir<N> as AlgebraicSponge<Fq<N>, 2>>:
 pub(crate) type VarunaProvingKey<N> = CircuitProvingKey<<N as Environment>::PairingCurve, VarunaHidingMode>;
 pub(crate) type VarunaVerifyingKey<N> = CircuitVerifyingKey<<N as Environment>::PairingCurve>;
 
+/// A helper to check the checkpoint interval of a network at compile time.
+struct CheckpointInterval<N: Network>(PhantomData<N>);
+
+impl<N: Network> CheckpointInterval<N> {
+    /// The checkpoint interval of the network, which must be strictly greater than zero.
+    const VALUE: u32 = {
+        assert!(N::CHECKPOINT_INTERVAL > 0, "The checkpoint interval must be strictly greater than zero");
+        N::CHECKPOINT_INTERVAL
+    };
+}
+
+/// Returns the domain separator with the given name, as declared in `N::DOMAIN_SEPARATORS`, to set up the network.
+/// This method panics if the domain separator is not declared, as the network cannot be set up without it.
+fn setup_domain<N: Network>(name: &str) -> &'static str {
+    N::domain_separator(name).unwrap_or_else(|| panic!("The '{name}' domain separator is not declared for {}", N::NAME))
+}
+
 pub trait Network:
     'static
     + Environment
@@ -109,13 +132,24 @@ pub trait Network:
     /// This is deliberately set to a low value (8) for testing purposes only.
     #[cfg(feature = "test")]
     const GENESIS_PROOF_TARGET: u64 = 1u64 << 3;
+    /// The maximum factor by which the coinbase target may change in a single retarget.
+    const MAX_RETARGET_FACTOR: u64 = 4;
     /// The maximum number of solutions that can be included per block as a power of 2.
     const MAX_SOLUTIONS_AS_POWER_OF_TWO: u8 = 2; // 4 solutions
     /// The maximum number of solutions that can be included per block.
     const MAX_SOLUTIONS: usize = 1 << Self::MAX_SOLUTIONS_AS_POWER_OF_TWO; // 4 solutions
+    /// The maximum number of prover solutions that can be included per block.
+    const MAX_PROVER_SOLUTIONS: usize = Self::MAX_SOLUTIONS;
+    /// The degree of the coinbase puzzle polynomial.
+    const COINBASE_PUZZLE_DEGREE: u32 = (1 << 13) - 1; // 8,191
+    /// The percentage of the block reward that is paid to provers, with the remainder paid to validators.
+    const PROVER_REWARD_PERCENTAGE: u8 = 66;
 
     /// The starting supply of Aleo credits.
     const STARTING_SUPPLY: u64 = 1_500_000_000_000_000; // 1.5B credits
+    /// The maximum amount in microcredits of a single transfer, which defaults to the starting supply (i.e. no cap).
+    /// Development and test networks may lower this, to guard against accidentally large transfers.
+    const MAX_CREDITS_PER_TRANSFER: u64 = Self::STARTING_SUPPLY;
     /// The cost in microcredits per byte for the deployment transaction.
     const DEPLOYMENT_FEE_MULTIPLIER: u64 = 1_000; // 1 millicredit per byte
     /// The constant that divides the storage polynomial.
@@ -141,6 +175,9 @@ pub trait Network:
     const BLOCK_TIME: u16 = 10;
     /// The number of blocks per epoch.
     const NUM_BLOCKS_PER_EPOCH: u32 = 3600 / Self::BLOCK_TIME as u32; // 360 blocks == ~1 hour
+    /// The number of blocks between consecutive checkpoints.
+    /// Note: This value must be strictly greater than zero, which is checked at compile time.
+    const CHECKPOINT_INTERVAL: u32 = 10_000;
 
     /// The maximum number of entries in data.
     const MAX_DATA_ENTRIES: usize = 32;
@@ -166,11 +203,15 @@ pub trait Network:
     /// The maximum number of entries in a record.
     const MAX_RECORD_ENTRIES: usize = Self::MIN_RECORD_ENTRIES.saturating_add(Self::MAX_DATA_ENTRIES);
 
+    /// The network-level domain of program IDs, as in `{name}.{suffix}`.
+    const PROGRAM_SUFFIX: &'static str = "aleo";
     /// The maximum program size by number of characters.
     const MAX_PROGRAM_SIZE: usize = 100_000; // 100 KB
 
     /// The maximum number of mappings in a program.
     const MAX_MAPPINGS: usize = 31;
+    /// The maximum number of key-value entries in a mapping.
+    const MAX_MAPPING_ENTRIES: u64 = u32::MAX as u64;
     /// The maximum number of functions in a program.
     const MAX_FUNCTIONS: usize = 31;
     /// The maximum number of structs in a program.
@@ -192,6 +233,9 @@ pub trait Network:
     const MAX_INPUTS: usize = 16;
     /// The maximum number of outputs per transition.
     const MAX_OUTPUTS: usize = 16;
+    /// The maximum number of transitions per transaction.
+    /// This is one more than the maximum number of functions, to account for the fee transition.
+    const MAX_TRANSITIONS: usize = Self::MAX_FUNCTIONS + 1;
 
     /// The maximum program depth.
     const MAX_PROGRAM_DEPTH: usize = 64;
@@ -205,6 +249,32 @@ pub trait Network:
     // Note: This value must **not** be decreased as it would invalidate existing transactions.
     const MAX_TRANSACTION_SIZE: usize = 128_000; // 128 kB
 
+    /// The domain separators of the network, as `(name, separator)` pairs.
+    /// These strings derive the account generator and domain constants, and set up the hash functions of the network,
+    /// so a network may override them to use its own domain separation.
+    const DOMAIN_SEPARATORS: &'static [(&'static str, &'static str)] = &[
+        ("account", "AleoAccountEncryptionAndSignatureScheme0"),
+        ("encryption", "AleoSymmetricEncryption0"),
+        ("graph_key", "AleoGraphKey0"),
+        ("serial_number", "AleoSerialNumber0"),
+        ("bhp256", "AleoBHP256"),
+        ("bhp512", "AleoBHP512"),
+        ("bhp768", "AleoBHP768"),
+        ("bhp1024", "AleoBHP1024"),
+        ("ped64", "AleoPedersen64"),
+        ("ped128", "AleoPedersen128"),
+        ("psd2", "AleoPoseidon2"),
+        ("psd4", "AleoPoseidon4"),
+        ("psd8", "AleoPoseidon8"),
+    ];
+
+    /// The rate of the Poseidon sponge used for the Varuna Fiat-Shamir transform.
+    /// Note: This must match the rate of `FiatShamir`.
+    const POSEIDON_RATE: usize = 2;
+    /// The capacity of the Poseidon sponge used for the Varuna Fiat-Shamir transform.
+    /// Note: This must match the capacity of `FiatShamir`.
+    const POSEIDON_CAPACITY: usize = 1;
+
     /// The state root type.
     type StateRoot: Bech32ID<Field<Self>>;
     /// The block hash type.
@@ -405,4 +475,147 @@ pub trait Network:
         root: &Field<Self>,
         leaf: &Vec<Field<Self>>,
     ) -> bool;
+
+    /// Returns `true` if the given block height is a checkpoint height.
+    fn is_checkpoint_height(height: u32) -> bool {
+        height % CheckpointInterval::<Self>::VALUE == 0
+    }
+
+    /// Returns the nearest checkpoint height that is less than or equal to the given block height.
+    fn nearest_checkpoint(height: u32) -> u32 {
+        height - (height % CheckpointInterval::<Self>::VALUE)
+    }
+
+    /// Returns the evaluation domain size for the coinbase puzzle polynomial,
+    /// which is the smallest power of two that can hold all `COINBASE_PUZZLE_DEGREE + 1` coefficients.
+    fn puzzle_domain_size() -> usize {
+        (Self::COINBASE_PUZZLE_DEGREE as usize).saturating_add(1).next_power_of_two()
+    }
+
+    /// Returns `true` if the given number of transitions fits within a single transaction.
+    fn within_transition_limit(num_transitions: usize) -> bool {
+        num_transitions <= Self::MAX_TRANSITIONS
+    }
+
+    /// Returns `true` if a mapping with the given number of entries has capacity for another entry.
+    fn within_mapping_capacity(num_entries: u64) -> bool {
+        num_entries < Self::MAX_MAPPING_ENTRIES
+    }
+
+    /// Returns `true` if a transfer of the given amount in microcredits is within `MAX_CREDITS_PER_TRANSFER`.
+    fn is_transfer_within_limit(amount: u64) -> bool {
+        amount <= Self::MAX_CREDITS_PER_TRANSFER
+    }
+
+    /// Returns the fork-choice weight of the block at the given height, with the given cumulative work.
+    /// By default, the weight is the cumulative work; networks may override this to weigh blocks differently.
+    fn block_weight(cumulative_work: u128, _height: u32) -> u128 {
+        cumulative_work
+    }
+
+    /// Returns the address group element from the given bytes, which must be prefixed by the
+    /// little-endian network ID. This guards against decoding an address from another network.
+    fn address_from_bytes_checked(bytes: &[u8]) -> Result<Group<Self>> {
+        // Ensure the bytes contain the network ID prefix and the group element.
+        ensure!(
+            bytes.len() == 2 + Group::<Self>::size_in_bytes(),
+            "Expected {} bytes for an address, found {} bytes",
+            2 + Group::<Self>::size_in_bytes(),
+            bytes.len()
+        );
+        // Ensure the network ID matches.
+        let network_id = u16::from_le_bytes([bytes[0], bytes[1]]);
+        ensure!(network_id == Self::ID, "Expected an address for network '{}', found network '{network_id}'", Self::ID);
+        // Decode the group element.
+        Group::from_bytes_le(&bytes[2..])
+    }
+
+    /// Returns the canonical little-endian bit encoding of the given address group element.
+    /// This is the compressed encoding of the point, i.e. the bits of its x-coordinate, as used to hash addresses.
+    fn address_to_bits_le(address: &Group<Self>) -> Vec<bool> {
+        address.to_x_coordinate().to_bits_le()
+    }
+
+    /// Returns the transaction ID, by computing the root of a BHP Merkle tree of the given transaction leaves.
+    /// Note that `DEPTH` must match the transaction tree depth (i.e. `TRANSACTION_DEPTH`).
+    fn compute_transaction_id<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<Self::TransactionID> {
+        Ok((*Self::merkle_tree_bhp::<DEPTH>(leaves)?.root()).into())
+    }
+
+    /// Returns `true` if the given transaction ID matches the ID recomputed from the given transaction leaves.
+    fn verify_transaction_id<const DEPTH: u8>(
+        transaction_id: &Self::TransactionID,
+        leaves: &[Vec<bool>],
+    ) -> Result<bool> {
+        Ok(*transaction_id == Self::compute_transaction_id::<DEPTH>(leaves)?)
+    }
+
+    /// Returns the checksum of a record ciphertext, by hashing the given ciphertext bits with BHP-1024.
+    /// This is the checksum committed to by record outputs, and identifies the encrypted record.
+    fn compute_ciphertext_checksum(ciphertext_bits: &[bool]) -> Result<Field<Self>> {
+        Self::hash_bhp1024(ciphertext_bits)
+    }
+
+    /// Returns `true` if the given checksum matches the checksum recomputed from the given ciphertext bits.
+    fn verify_ciphertext_checksum(checksum: &Field<Self>, ciphertext_bits: &[bool]) -> Result<bool> {
+        Ok(*checksum == Self::compute_ciphertext_checksum(ciphertext_bits)?)
+    }
+
+    /// Returns the width of the Poseidon permutation, i.e. the number of field elements in the sponge state.
+    fn poseidon_permutation_width() -> usize {
+        Self::POSEIDON_RATE + Self::POSEIDON_CAPACITY
+    }
+
+    /// Returns the commitment to the given input under the given randomness, using the canonical BHP-512 scheme.
+    fn compute_commitment(input: &[bool], randomness: &Scalar<Self>) -> Result<Field<Self>> {
+        Self::commit_bhp512(input, randomness)
+    }
+
+    /// Returns the serial number of a record, from the signature secret key `sk_sig` and the record commitment.
+    fn compute_serial_number(sk_sig: &Scalar<Self>, commitment: Field<Self>) -> Result<Field<Self>> {
+        // Compute the generator `H` as `HashToGroup(commitment)`.
+        let h = Self::hash_to_group_psd2(&[Self::serial_number_domain(), commitment])?;
+        // Compute `gamma` as `sk_sig * H`.
+        let gamma = h * *sk_sig;
+        // Compute the serial number from `gamma`.
+        Self::compute_serial_number_from_gamma(&gamma, commitment)
+    }
+
+    /// Returns the serial number of a record, from the commitment and `gamma`, i.e. `sk_sig * HashToGroup(commitment)`.
+    fn compute_serial_number_from_gamma(gamma: &Group<Self>, commitment: Field<Self>) -> Result<Field<Self>> {
+        // Compute `sn_nonce` as `Hash(COFACTOR * gamma)`.
+        let sn_nonce =
+            Self::hash_to_scalar_psd2(&[Self::serial_number_domain(), gamma.mul_by_cofactor().to_x_coordinate()])?;
+        // Compute `serial_number` as `Commit(commitment, sn_nonce)`.
+        Self::compute_commitment(&(Self::serial_number_domain(), commitment).to_bits_le(), &sn_nonce)
+    }
+
+    /// Returns the next coinbase target, scaling the previous target by the ratio of the expected to the actual time.
+    /// Faster blocks raise the target, and slower blocks lower it, by at most `MAX_RETARGET_FACTOR` in either direction.
+    fn retarget(prev_target: u64, actual_time: u32, expected_time: u32) -> u64 {
+        // Note: A zero target or time is treated as 1, to keep the adjustment well-defined.
+        let prev_target = u128::from(prev_target.max(1));
+        let (actual_time, expected_time) = (u128::from(actual_time.max(1)), u128::from(expected_time.max(1)));
+        // Scale the previous target by the ratio of the expected time to the actual time.
+        let target = prev_target * expected_time / actual_time;
+        // Bound the adjustment to the maximum retarget factor.
+        let factor = u128::from(Self::MAX_RETARGET_FACTOR.max(1));
+        let target = target.clamp((prev_target / factor).max(1), prev_target * factor);
+        u64::try_from(target).unwrap_or(u64::MAX)
+    }
+
+    /// Returns the `(validator_share, prover_share)` of the given block reward, per `PROVER_REWARD_PERCENTAGE`.
+    /// The prover share is rounded down, so any remainder is paid to validators, and the shares sum to the total.
+    fn split_block_reward(total: u64) -> (u64, u64) {
+        // Note: A percentage above 100 is treated as 100.
+        let percentage = u64::from(Self::PROVER_REWARD_PERCENTAGE.min(100));
+        // Compute `floor(total * percentage / 100)`, split by the quotient and remainder to avoid overflow.
+        let prover_share = (total / 100) * percentage + (total % 100) * percentage / 100;
+        (total - prover_share, prover_share)
+    }
+
+    /// Returns the domain separator with the given name, if it exists.
+    fn domain_separator(name: &str) -> Option<&'static str> {
+        Self::DOMAIN_SEPARATORS.iter().find_map(|(key, separator)| (*key == name).then_some(*separator))
+    }
 }
@@ -34,38 +34,38 @@ use snarkvm_console_algorithms::{
 
 lazy_static! {
     /// The group bases for the Aleo signature and encryption schemes.
-    pub static ref GENERATOR_G: Vec<Group<MainnetV0 >> = MainnetV0::new_bases("AleoAccountEncryptionAndSignatureScheme0");
+    pub static ref GENERATOR_G: Vec<Group<MainnetV0 >> = MainnetV0::new_bases(setup_domain::<MainnetV0>("account"));
 
     /// The Varuna sponge parameters.
     pub static ref VARUNA_FS_PARAMETERS: FiatShamirParameters<MainnetV0> = FiatShamir::<MainnetV0>::sample_parameters();
 
     /// The encryption domain as a constant field element.
-    pub static ref ENCRYPTION_DOMAIN: Field<MainnetV0> = Field::<MainnetV0>::new_domain_separator("AleoSymmetricEncryption0");
+    pub static ref ENCRYPTION_DOMAIN: Field<MainnetV0> = Field::<MainnetV0>::new_domain_separator(setup_domain::<MainnetV0>("encryption"));
     /// The graph key domain as a constant field element.
-    pub static ref GRAPH_KEY_DOMAIN: Field<MainnetV0> = Field::<MainnetV0>::new_domain_separator("AleoGraphKey0");
+    pub static ref GRAPH_KEY_DOMAIN: Field<MainnetV0> = Field::<MainnetV0>::new_domain_separator(setup_domain::<MainnetV0>("graph_key"));
     /// The serial number domain as a constant field element.
-    pub static ref SERIAL_NUMBER_DOMAIN: Field<MainnetV0> = Field::<MainnetV0>::new_domain_separator("AleoSerialNumber0");
+    pub static ref SERIAL_NUMBER_DOMAIN: Field<MainnetV0> = Field::<MainnetV0>::new_domain_separator(setup_domain::<MainnetV0>("serial_number"));
 
     /// The BHP hash function, which can take an input of up to 256 bits.
-    pub static ref BHP_256: BHP256<MainnetV0> = BHP256::<MainnetV0>::setup("AleoBHP256").expect("Failed to setup BHP256");
+    pub static ref BHP_256: BHP256<MainnetV0> = BHP256::<MainnetV0>::setup(setup_domain::<MainnetV0>("bhp256")).expect("Failed to setup BHP256");
     /// The BHP hash function, which can take an input of up to 512 bits.
-    pub static ref BHP_512: BHP512<MainnetV0> = BHP512::<MainnetV0>::setup("AleoBHP512").expect("Failed to setup BHP512");
+    pub static ref BHP_512: BHP512<MainnetV0> = BHP512::<MainnetV0>::setup(setup_domain::<MainnetV0>("bhp512")).expect("Failed to setup BHP512");
     /// The BHP hash function, which can take an input of up to 768 bits.
-    pub static ref BHP_768: BHP768<MainnetV0> = BHP768::<MainnetV0>::setup("AleoBHP768").expect("Failed to setup BHP768");
+    pub static ref BHP_768: BHP768<MainnetV0> = BHP768::<MainnetV0>::setup(setup_domain::<MainnetV0>("bhp768")).expect("Failed to setup BHP768");
     /// The BHP hash function, which can take an input of up to 1024 bits.
-    pub static ref BHP_1024: BHP1024<MainnetV0> = BHP1024::<MainnetV0>::setup("AleoBHP1024").expect("Failed to setup BHP1024");
+    pub static ref BHP_1024: BHP1024<MainnetV0> = BHP1024::<MainnetV0>::setup(setup_domain::<MainnetV0>("bhp1024")).expect("Failed to setup BHP1024");
 
     /// The Pedersen hash function, which can take an input of up to 64 bits.
-    pub static ref PEDERSEN_64: Pedersen64<MainnetV0> = Pedersen64::<MainnetV0>::setup("AleoPedersen64");
+    pub static ref PEDERSEN_64: Pedersen64<MainnetV0> = Pedersen64::<MainnetV0>::setup(setup_domain::<MainnetV0>("ped64"));
     /// The Pedersen hash function, which can take an input of up to 128 bits.
-    pub static ref PEDERSEN_128: Pedersen128<MainnetV0> = Pedersen128::<MainnetV0>::setup("AleoPedersen128");
+    pub static ref PEDERSEN_128: Pedersen128<MainnetV0> = Pedersen128::<MainnetV0>::setup(setup_domain::<MainnetV0>("ped128"));
 
     /// The Poseidon hash function, using a rate of 2.
-    pub static ref POSEIDON_2: Poseidon2<MainnetV0> = Poseidon2::<MainnetV0>::setup("AleoPoseidon2").expect("Failed to setup Poseidon2");
+    pub static ref POSEIDON_2: Poseidon2<MainnetV0> = Poseidon2::<MainnetV0>::setup(setup_domain::<MainnetV0>("psd2")).expect("Failed to setup Poseidon2");
     /// The Poseidon hash function, using a rate of 4.
-    pub static ref POSEIDON_4: Poseidon4<MainnetV0> = Poseidon4::<MainnetV0>::setup("AleoPoseidon4").expect("Failed to setup Poseidon4");
+    pub static ref POSEIDON_4: Poseidon4<MainnetV0> = Poseidon4::<MainnetV0>::setup(setup_domain::<MainnetV0>("psd4")).expect("Failed to setup Poseidon4");
     /// The Poseidon hash function, using a rate of 8.
-    pub static ref POSEIDON_8: Poseidon8<MainnetV0> = Poseidon8::<MainnetV0>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
+    pub static ref POSEIDON_8: Poseidon8<MainnetV0> = Poseidon8::<MainnetV0>::setup(setup_domain::<MainnetV0>("psd8")).expect("Failed to setup Poseidon8");
 
     pub static ref CREDITS_PROVING_KEYS: IndexMap<String, Arc<VarunaProvingKey<Console>>> = {
         let mut map = IndexMap::new();
@@ -517,4 +517,254 @@ mod tests {
         let group = CurrentNetwork::g_scalar_multiply(&scalar);
         assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
     }
+
+    #[test]
+    fn test_checkpoints() {
+        let interval = CurrentNetwork::CHECKPOINT_INTERVAL;
+
+        // Check the checkpoint detection around the interval boundaries.
+        assert!(CurrentNetwork::is_checkpoint_height(0));
+        assert!(!CurrentNetwork::is_checkpoint_height(1));
+        assert!(!CurrentNetwork::is_checkpoint_height(interval - 1));
+        assert!(CurrentNetwork::is_checkpoint_height(interval));
+        assert!(!CurrentNetwork::is_checkpoint_height(interval + 1));
+        assert!(CurrentNetwork::is_checkpoint_height(2 * interval));
+
+        // Check the nearest checkpoint around the interval boundaries.
+        assert_eq!(CurrentNetwork::nearest_checkpoint(0), 0);
+        assert_eq!(CurrentNetwork::nearest_checkpoint(1), 0);
+        assert_eq!(CurrentNetwork::nearest_checkpoint(interval - 1), 0);
+        assert_eq!(CurrentNetwork::nearest_checkpoint(interval), interval);
+        assert_eq!(CurrentNetwork::nearest_checkpoint(interval + 1), interval);
+        assert_eq!(CurrentNetwork::nearest_checkpoint(2 * interval - 1), interval);
+        assert_eq!(CurrentNetwork::nearest_checkpoint(u32::MAX), u32::MAX - (u32::MAX % interval));
+    }
+
+    #[test]
+    fn test_puzzle_domain_size() {
+        let degree = CurrentNetwork::COINBASE_PUZZLE_DEGREE as usize;
+        let domain_size = CurrentNetwork::puzzle_domain_size();
+
+        // Ensure the domain size is the smallest power of two that fits every coefficient.
+        assert_eq!(domain_size, 8192);
+        assert!(domain_size.is_power_of_two());
+        assert!(domain_size > degree);
+        assert!(domain_size / 2 <= degree);
+
+        // Ensure the prover solutions fit within a block.
+        assert!(CurrentNetwork::MAX_PROVER_SOLUTIONS <= CurrentNetwork::MAX_SOLUTIONS);
+    }
+
+    #[test]
+    fn test_transition_limit() {
+        let max = CurrentNetwork::MAX_TRANSITIONS;
+        // Ensure the limit accounts for every function and the fee transition.
+        assert_eq!(max, CurrentNetwork::MAX_FUNCTIONS + 1);
+
+        // Check the transition limit around the boundary.
+        assert!(CurrentNetwork::within_transition_limit(1));
+        assert!(CurrentNetwork::within_transition_limit(max - 1));
+        assert!(CurrentNetwork::within_transition_limit(max));
+        assert!(!CurrentNetwork::within_transition_limit(max + 1));
+    }
+
+    #[test]
+    fn test_mapping_capacity() {
+        let max = CurrentNetwork::MAX_MAPPING_ENTRIES;
+        // Check the mapping capacity around the boundary.
+        assert!(CurrentNetwork::within_mapping_capacity(0));
+        assert!(CurrentNetwork::within_mapping_capacity(max - 1));
+        assert!(!CurrentNetwork::within_mapping_capacity(max));
+        assert!(!CurrentNetwork::within_mapping_capacity(max + 1));
+    }
+
+    #[test]
+    fn test_transfer_limit() {
+        let max = CurrentNetwork::MAX_CREDITS_PER_TRANSFER;
+        // Check the transfer limit around the cap.
+        assert!(CurrentNetwork::is_transfer_within_limit(0));
+        assert!(CurrentNetwork::is_transfer_within_limit(max - 1));
+        assert!(CurrentNetwork::is_transfer_within_limit(max));
+        assert!(!CurrentNetwork::is_transfer_within_limit(max + 1));
+    }
+
+    #[test]
+    fn test_block_weight() {
+        // Ensure the default weight is the cumulative work, regardless of the height.
+        for (cumulative_work, height) in [(0, 0), (1, 1), (123_456_789, 42), (u128::MAX, u32::MAX)] {
+            assert_eq!(CurrentNetwork::block_weight(cumulative_work, height), cumulative_work);
+        }
+    }
+
+    #[test]
+    fn test_address_from_bytes_checked() -> Result<()> {
+        let address = Group::<CurrentNetwork>::generator();
+
+        // Ensure an address prefixed with this network ID is decoded.
+        let bytes = [CurrentNetwork::ID.to_le_bytes().to_vec(), address.to_bytes_le()?].concat();
+        assert_eq!(CurrentNetwork::address_from_bytes_checked(&bytes)?, address);
+
+        // Ensure an address prefixed with another network ID is rejected.
+        let bytes = [TestnetV0::ID.to_le_bytes().to_vec(), address.to_bytes_le()?].concat();
+        assert!(CurrentNetwork::address_from_bytes_checked(&bytes).is_err());
+
+        // Ensure an address without the network ID prefix is rejected.
+        assert!(CurrentNetwork::address_from_bytes_checked(&address.to_bytes_le()?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_address_to_bits_le() {
+        let rng = &mut TestRng::default();
+
+        for address in [Group::<CurrentNetwork>::generator(), Group::rand(rng)] {
+            // Ensure the encoding is the size of a compressed point, and matches its x-coordinate.
+            let bits = CurrentNetwork::address_to_bits_le(&address);
+            assert_eq!(bits.len(), Field::<CurrentNetwork>::size_in_bits());
+            assert_eq!(bits, address.to_x_coordinate().to_bits_le());
+        }
+    }
+
+    #[test]
+    fn test_transaction_id() -> Result<()> {
+        const DEPTH: u8 = 5;
+
+        // Sample the transaction leaves.
+        let rng = &mut TestRng::default();
+        let leaves = (0..3).map(|_| Field::<CurrentNetwork>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+
+        // Ensure the recomputed ID matches the root of the transaction tree.
+        let expected = <CurrentNetwork as Network>::TransactionID::from(
+            *CurrentNetwork::merkle_tree_bhp::<DEPTH>(&leaves)?.root(),
+        );
+        assert_eq!(CurrentNetwork::compute_transaction_id::<DEPTH>(&leaves)?, expected);
+        assert!(CurrentNetwork::verify_transaction_id::<DEPTH>(&expected, &leaves)?);
+
+        // Ensure the ID does not verify against different leaves.
+        assert!(!CurrentNetwork::verify_transaction_id::<DEPTH>(&expected, &leaves[..2])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ciphertext_checksum() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let ciphertext = (0..2).flat_map(|_| Field::<CurrentNetwork>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+
+        // Ensure the checksum is deterministic, and matches the BHP-1024 hash.
+        let checksum = CurrentNetwork::compute_ciphertext_checksum(&ciphertext)?;
+        assert_eq!(checksum, CurrentNetwork::compute_ciphertext_checksum(&ciphertext)?);
+        assert_eq!(checksum, CurrentNetwork::hash_bhp1024(&ciphertext)?);
+        assert!(CurrentNetwork::verify_ciphertext_checksum(&checksum, &ciphertext)?);
+
+        // Ensure the checksum does not verify against another ciphertext.
+        let other = (0..2).flat_map(|_| Field::<CurrentNetwork>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+        assert!(!CurrentNetwork::verify_ciphertext_checksum(&checksum, &other)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_domain_separator() {
+        // Ensure the lookups return the expected separators.
+        assert_eq!(CurrentNetwork::domain_separator("account"), Some("AleoAccountEncryptionAndSignatureScheme0"));
+        assert_eq!(CurrentNetwork::domain_separator("encryption"), Some("AleoSymmetricEncryption0"));
+        assert_eq!(CurrentNetwork::domain_separator("bhp1024"), Some("AleoBHP1024"));
+        assert_eq!(CurrentNetwork::domain_separator("psd8"), Some("AleoPoseidon8"));
+        assert_eq!(CurrentNetwork::domain_separator("unknown"), None);
+
+        // Ensure the separators match the domain constants of the network.
+        let domain =
+            |name: &str| Field::<CurrentNetwork>::new_domain_separator(CurrentNetwork::domain_separator(name).unwrap());
+        assert_eq!(domain("encryption"), CurrentNetwork::encryption_domain());
+        assert_eq!(domain("graph_key"), CurrentNetwork::graph_key_domain());
+        assert_eq!(domain("serial_number"), CurrentNetwork::serial_number_domain());
+
+        // Ensure the names are unique.
+        let names =
+            CurrentNetwork::DOMAIN_SEPARATORS.iter().map(|(name, _)| name).collect::<std::collections::HashSet<_>>();
+        assert_eq!(names.len(), CurrentNetwork::DOMAIN_SEPARATORS.len());
+    }
+
+    #[test]
+    fn test_poseidon_permutation_width() {
+        // Ensure the width is the rate plus the capacity.
+        let width = CurrentNetwork::poseidon_permutation_width();
+        assert_eq!(width, CurrentNetwork::POSEIDON_RATE + CurrentNetwork::POSEIDON_CAPACITY);
+        assert_eq!(width, 3);
+
+        // Ensure the width matches the round keys of the Fiat-Shamir sponge parameters.
+        let parameters = CurrentNetwork::varuna_fs_parameters();
+        assert!(parameters.ark.iter().all(|round_keys| round_keys.len() == width));
+        assert!(parameters.mds.iter().all(|row| row.len() == width));
+    }
+
+    #[test]
+    fn test_compute_commitment() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let input = Field::<CurrentNetwork>::rand(rng).to_bits_le();
+        let randomness = Scalar::rand(rng);
+
+        // Ensure the commitment is deterministic, and matches the BHP-512 commitment.
+        let commitment = CurrentNetwork::compute_commitment(&input, &randomness)?;
+        assert_eq!(commitment, CurrentNetwork::compute_commitment(&input, &randomness)?);
+        assert_eq!(commitment, CurrentNetwork::commit_bhp512(&input, &randomness)?);
+
+        // Ensure the commitment depends on the randomness.
+        assert_ne!(commitment, CurrentNetwork::compute_commitment(&input, &Scalar::rand(rng))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compute_serial_number() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let (sk_sig, commitment) = (Scalar::rand(rng), Field::rand(rng));
+
+        // Ensure the serial number is deterministic.
+        let serial_number = CurrentNetwork::compute_serial_number(&sk_sig, commitment)?;
+        assert_eq!(serial_number, CurrentNetwork::compute_serial_number(&sk_sig, commitment)?);
+
+        // Ensure the serial number depends on both the secret key and the commitment.
+        assert_ne!(serial_number, CurrentNetwork::compute_serial_number(&Scalar::rand(rng), commitment)?);
+        assert_ne!(serial_number, CurrentNetwork::compute_serial_number(&sk_sig, Field::rand(rng))?);
+
+        // Ensure the serial number matches the one derived from `gamma`.
+        let gamma = CurrentNetwork::hash_to_group_psd2(&[CurrentNetwork::serial_number_domain(), commitment])? * sk_sig;
+        assert_eq!(serial_number, CurrentNetwork::compute_serial_number_from_gamma(&gamma, commitment)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_retarget() {
+        let target = CurrentNetwork::GENESIS_COINBASE_TARGET;
+        let block_time = u32::from(CurrentNetwork::BLOCK_TIME);
+        let factor = CurrentNetwork::MAX_RETARGET_FACTOR;
+
+        // Ensure the target is unchanged for blocks on time.
+        assert_eq!(CurrentNetwork::retarget(target, block_time, block_time), target);
+        // Ensure the target rises for fast blocks, within the bound.
+        assert_eq!(CurrentNetwork::retarget(target, block_time / 2, block_time), target * 2);
+        assert_eq!(CurrentNetwork::retarget(target, 0, block_time), target * factor);
+        // Ensure the target falls for slow blocks, within the bound.
+        assert_eq!(CurrentNetwork::retarget(target, block_time * 2, block_time), target / 2);
+        assert_eq!(CurrentNetwork::retarget(target, u32::MAX, block_time), target / factor);
+
+        // Ensure the target saturates, and never falls to zero.
+        assert_eq!(CurrentNetwork::retarget(u64::MAX, 1, block_time), u64::MAX);
+        assert_eq!(CurrentNetwork::retarget(1, u32::MAX, block_time), 1);
+    }
+
+    #[test]
+    fn test_split_block_reward() {
+        let percentage = u64::from(CurrentNetwork::PROVER_REWARD_PERCENTAGE);
+
+        // Ensure the shares sum to the total, and the prover share respects the percentage.
+        for total in [0, 1, 2, 3, 99, 100, 101, 1_000_000, u64::MAX] {
+            let (validator_share, prover_share) = CurrentNetwork::split_block_reward(total);
+            assert_eq!(validator_share + prover_share, total);
+            assert_eq!(u128::from(prover_share), u128::from(total) * u128::from(percentage) / 100);
+        }
+
+        // Ensure the remainder is paid to validators.
+        assert_eq!(CurrentNetwork::split_block_reward(100), (100 - percentage, percentage));
+        assert_eq!(CurrentNetwork::split_block_reward(1), (1, 0));
+    }
 }
@@ -22,6 +22,7 @@ mod parse;
 mod serialize;
 mod to_bits;
 mod to_fields;
+mod zeroize;
 
 use crate::{Access, Identifier, Plaintext, ProgramID, Value};
 use snarkvm_console_network::Network;
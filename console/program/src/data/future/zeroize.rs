@@ -0,0 +1,29 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use ::zeroize::Zeroize;
+
+impl<N: Network> Zeroize for Future<N> {
+    /// Overwrites every literal in the arguments of the future with the zero value of its type.
+    /// The program ID and function name are preserved.
+    fn zeroize(&mut self) {
+        for argument in self.arguments.iter_mut() {
+            match argument {
+                Argument::Plaintext(plaintext) => plaintext.zeroize(),
+                Argument::Future(future) => future.zeroize(),
+            }
+        }
+    }
+}
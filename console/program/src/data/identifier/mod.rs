@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod bytes;
+mod compare;
 mod equal;
 mod from_bits;
 mod from_field;
@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use ::zeroize::Zeroize;
+
+impl<N: Network> Zeroize for Literal<N> {
+    /// Overwrites the literal with the zero value of its type.
+    fn zeroize(&mut self) {
+        match self {
+            Self::Address(address) => *address = Address::zero(),
+            Self::Boolean(boolean) => *boolean = Boolean::new(false),
+            Self::Field(field) => field.zeroize(),
+            Self::Group(group) => *group = Group::zero(),
+            Self::I8(integer) => *integer = I8::zero(),
+            Self::I16(integer) => *integer = I16::zero(),
+            Self::I32(integer) => *integer = I32::zero(),
+            Self::I64(integer) => *integer = I64::zero(),
+            Self::I128(integer) => *integer = I128::zero(),
+            Self::U8(integer) => *integer = U8::zero(),
+            Self::U16(integer) => *integer = U16::zero(),
+            Self::U32(integer) => *integer = U32::zero(),
+            Self::U64(integer) => *integer = U64::zero(),
+            Self::U128(integer) => *integer = U128::zero(),
+            Self::Scalar(scalar) => scalar.zeroize(),
+            Self::Signature(signature) => **signature = Signature::zero(),
+            Self::String(string) => string.zeroize(),
+        }
+    }
+}
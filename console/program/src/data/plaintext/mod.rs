@@ -24,6 +24,7 @@ mod serialize;
 mod size_in_fields;
 mod to_bits;
 mod to_fields;
+mod zeroize;
 
 use crate::{Access, Ciphertext, Identifier, Literal};
 use snarkvm_console_network::Network;
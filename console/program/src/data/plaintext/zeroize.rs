@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use ::zeroize::Zeroize;
+
+impl<N: Network> Zeroize for Plaintext<N> {
+    /// Overwrites every literal in the plaintext with the zero value of its type, and clears the cached bits.
+    /// The structure of the plaintext, i.e. its member names and array lengths, is preserved.
+    fn zeroize(&mut self) {
+        let bits_le = match self {
+            Self::Literal(literal, bits_le) => {
+                literal.zeroize();
+                bits_le
+            }
+            Self::Struct(members, bits_le) => {
+                members.values_mut().for_each(Zeroize::zeroize);
+                bits_le
+            }
+            Self::Array(elements, bits_le) => {
+                elements.iter_mut().for_each(Zeroize::zeroize);
+                bits_le
+            }
+        };
+        // Clear the cached bits, which encode the original literals.
+        if let Some(mut bits_le) = bits_le.take() {
+            bits_le.zeroize();
+        }
+    }
+}
@@ -33,6 +33,7 @@ mod tag;
 mod to_bits;
 mod to_commitment;
 mod to_fields;
+mod zeroize;
 
 use crate::{Access, Ciphertext, Identifier, Literal, Plaintext, ProgramID};
 use snarkvm_console_account::{Address, PrivateKey, ViewKey};
@@ -17,19 +17,11 @@ use super::*;
 impl<N: Network, Private: Visibility> Record<N, Private> {
     /// A helper method to derive the serial number from the private key and commitment.
     pub fn serial_number(private_key: PrivateKey<N>, commitment: Field<N>) -> Result<Field<N>> {
-        // Compute the generator `H` as `HashToGroup(commitment)`.
-        let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
-        // Compute `gamma` as `sk_sig * H`.
-        let gamma = h * private_key.sk_sig();
-        // Compute the serial number from `gamma`.
-        Self::serial_number_from_gamma(&gamma, commitment)
+        N::compute_serial_number(&private_key.sk_sig(), commitment)
     }
 
     /// A helper method to derive the serial number from the gamma and commitment.
     pub fn serial_number_from_gamma(gamma: &Group<N>, commitment: Field<N>) -> Result<Field<N>> {
-        // Compute `sn_nonce` as `Hash(COFACTOR * gamma)`.
-        let sn_nonce = N::hash_to_scalar_psd2(&[N::serial_number_domain(), gamma.mul_by_cofactor().to_x_coordinate()])?;
-        // Compute `serial_number` as `Commit(commitment, sn_nonce)`.
-        N::commit_bhp512(&(N::serial_number_domain(), commitment).to_bits_le(), &sn_nonce)
+        N::compute_serial_number_from_gamma(gamma, commitment)
     }
 }
@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use ::zeroize::Zeroize;
+
+impl<N: Network> Zeroize for Record<N, Plaintext<N>> {
+    /// Overwrites the owner, every entry, and the nonce of the record with zero values.
+    /// The entry names and visibilities of the record are preserved.
+    fn zeroize(&mut self) {
+        match &mut self.owner {
+            Owner::Public(address) => *address = Address::zero(),
+            Owner::Private(plaintext) => plaintext.zeroize(),
+        }
+        for entry in self.data.values_mut() {
+            match entry {
+                Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
+                    plaintext.zeroize()
+                }
+            }
+        }
+        self.nonce = Group::zero();
+    }
+}
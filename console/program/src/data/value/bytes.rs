@@ -82,4 +82,25 @@ mod tests {
         assert_eq!(expected, Value::read_le(&expected_bytes[..])?);
         Ok(())
     }
+
+    #[test]
+    fn test_value_write_le_appends() -> Result<()> {
+        let values = [
+            Value::<CurrentNetwork>::from_str("{ a: 1u8, b: [2u16, 3u16] }")?,
+            Value::from_str(
+                "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, token_amount: 100u64.private, _nonce: 0group.public }",
+            )?,
+        ];
+
+        // Ensure the bytes written into a shared buffer match the allocated bytes.
+        // Note: Callers that want to reuse an allocation should call `write_le` with a `&mut Vec<u8>`.
+        let mut buffer = Vec::new();
+        for value in &values {
+            let start = buffer.len();
+            value.write_le(&mut buffer)?;
+            assert_eq!(buffer[start..], value.to_bytes_le()?);
+        }
+        assert_eq!(buffer, [values[0].to_bytes_le()?, values[1].to_bytes_le()?].concat());
+        Ok(())
+    }
 }
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::LiteralType;
+
+impl<N: Network> Value<N> {
+    /// Returns a copy of the value, with the literal at the given path cast to the given literal type.
+    /// This method errors if the path does not lead to a literal, or if the cast is lossy or invalid,
+    /// i.e. when casting `300u32` to `u8`.
+    /// This method also errors if the path indexes into an array, as the elements of an array must share one type.
+    pub fn cast_leaf<A: Into<Access<N>> + Copy + Debug>(&self, path: &[A], target: LiteralType) -> Result<Self> {
+        // Ensure the path does not index into an array.
+        if path.iter().any(|access| matches!(Into::<Access<N>>::into(*access), Access::Index(_))) {
+            bail!("Cannot cast an array element in isolation, as found in path '{path:?}'")
+        }
+        self.map_leaf(path, |literal| literal.cast(target))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Identifier, U32};
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_cast_leaf() -> Result<()> {
+        let value = Value::<CurrentNetwork>::from_str("{ a: 5u8, b: [300u32, 7u32], c: 300u32 }")?;
+        let member = |name: &str| Access::<CurrentNetwork>::Member(Identifier::from_str(name).unwrap());
+        let index = |index: u32| Access::<CurrentNetwork>::Index(U32::new(index));
+
+        // Check a widening cast.
+        let expected = Value::from_str("{ a: 5u16, b: [300u32, 7u32], c: 300u32 }")?;
+        assert_eq!(value.cast_leaf(&[member("a")], LiteralType::U16)?, expected);
+
+        // Check a valid narrowing cast.
+        let expected = Value::from_str("{ a: 5u8, b: [300u32, 7u32], c: 300u16 }")?;
+        assert_eq!(value.cast_leaf(&[member("c")], LiteralType::U16)?, expected);
+
+        // Check an out-of-range narrowing cast.
+        assert!(value.cast_leaf(&[member("c")], LiteralType::U8).is_err());
+
+        // Check that casting an array element is rejected, as it would leave the array with mixed element types.
+        assert!(value.cast_leaf(&[member("b"), index(1)], LiteralType::U8).is_err());
+
+        // Check invalid paths.
+        assert!(value.cast_leaf(&[member("b")], LiteralType::U8).is_err());
+        assert!(value.cast_leaf(&[member("d")], LiteralType::U8).is_err());
+        assert!(value.cast_leaf(&[member("b"), index(2)], LiteralType::U8).is_err());
+
+        // Check a cast within a record, preserving the entry visibility.
+        let record = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 100u64.private, _nonce: 0group.public }",
+        )?;
+        let expected = Value::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 100u128.private, _nonce: 0group.public }",
+        )?;
+        assert_eq!(record.cast_leaf(&[member("amount")], LiteralType::U128)?, expected);
+        assert!(record.cast_leaf(&[member("owner")], LiteralType::Field).is_err());
+        Ok(())
+    }
+}
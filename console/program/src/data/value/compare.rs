@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Ord for Value<N> {
+    /// Returns the structural ordering of `self` and `other`.
+    /// Plaintext values are ordered before records, and records are ordered before futures.
+    /// Within a variant, values are compared member by member, in their declared order.
+    ///
+    /// Note: This ordering is structural, **not** semantic (i.e. literals are not compared numerically
+    /// across types, and groups are compared by their coordinates). It is consistent with `Eq`,
+    /// and exists to support deterministic sorted storage, such as keys in a `BTreeMap`.
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Plaintext(a), Self::Plaintext(b)) => cmp_plaintext(a, b),
+            (Self::Record(a), Self::Record(b)) => cmp_record(a, b),
+            (Self::Future(a), Self::Future(b)) => cmp_future(a, b),
+            (a, b) => value_rank(a).cmp(&value_rank(b)),
+        }
+    }
+}
+
+impl<N: Network> PartialOrd for Value<N> {
+    /// Returns the structural ordering of `self` and `other`.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Returns the rank of the variant of the given value.
+fn value_rank<N: Network>(value: &Value<N>) -> u8 {
+    match value {
+        Value::Plaintext(..) => 0,
+        Value::Record(..) => 1,
+        Value::Future(..) => 2,
+    }
+}
+
+/// Returns the lexicographic ordering of the given sequences, under the given element ordering.
+fn cmp_sequences<T>(
+    a: impl ExactSizeIterator<Item = T>,
+    b: impl ExactSizeIterator<Item = T>,
+    cmp: impl Fn(T, T) -> Ordering,
+) -> Ordering {
+    let lengths = a.len().cmp(&b.len());
+    a.zip(b).map(|(a, b)| cmp(a, b)).find(|ordering| ordering.is_ne()).unwrap_or(lengths)
+}
+
+/// Returns the structural ordering of the given plaintexts.
+/// Literals are ordered before structs, and structs are ordered before arrays.
+fn cmp_plaintext<N: Network>(a: &Plaintext<N>, b: &Plaintext<N>) -> Ordering {
+    match (a, b) {
+        (Plaintext::Literal(a, _), Plaintext::Literal(b, _)) => cmp_literal(a, b),
+        (Plaintext::Struct(a, _), Plaintext::Struct(b, _)) => {
+            cmp_sequences(a.iter(), b.iter(), |(name_a, a), (name_b, b)| {
+                name_a.cmp(name_b).then_with(|| cmp_plaintext(a, b))
+            })
+        }
+        (Plaintext::Array(a, _), Plaintext::Array(b, _)) => cmp_sequences(a.iter(), b.iter(), cmp_plaintext),
+        (a, b) => plaintext_rank(a).cmp(&plaintext_rank(b)),
+    }
+}
+
+/// Returns the rank of the variant of the given plaintext.
+fn plaintext_rank<N: Network>(plaintext: &Plaintext<N>) -> u8 {
+    match plaintext {
+        Plaintext::Literal(..) => 0,
+        Plaintext::Struct(..) => 1,
+        Plaintext::Array(..) => 2,
+    }
+}
+
+/// Returns the structural ordering of the given literals, which are ordered by their variant first.
+fn cmp_literal<N: Network>(a: &Literal<N>, b: &Literal<N>) -> Ordering {
+    match (a, b) {
+        (Literal::Address(a), Literal::Address(b)) => cmp_group(a, b),
+        (Literal::Boolean(a), Literal::Boolean(b)) => (**a).cmp(&**b),
+        (Literal::Field(a), Literal::Field(b)) => a.cmp(b),
+        (Literal::Group(a), Literal::Group(b)) => cmp_group(a, b),
+        (Literal::I8(a), Literal::I8(b)) => a.cmp(b),
+        (Literal::I16(a), Literal::I16(b)) => a.cmp(b),
+        (Literal::I32(a), Literal::I32(b)) => a.cmp(b),
+        (Literal::I64(a), Literal::I64(b)) => a.cmp(b),
+        (Literal::I128(a), Literal::I128(b)) => a.cmp(b),
+        (Literal::U8(a), Literal::U8(b)) => a.cmp(b),
+        (Literal::U16(a), Literal::U16(b)) => a.cmp(b),
+        (Literal::U32(a), Literal::U32(b)) => a.cmp(b),
+        (Literal::U64(a), Literal::U64(b)) => a.cmp(b),
+        (Literal::U128(a), Literal::U128(b)) => a.cmp(b),
+        (Literal::Scalar(a), Literal::Scalar(b)) => a.cmp(b),
+        (Literal::Signature(a), Literal::Signature(b)) => a
+            .challenge()
+            .cmp(&b.challenge())
+            .then_with(|| a.response().cmp(&b.response()))
+            .then_with(|| cmp_group(&a.compute_key().pk_sig(), &b.compute_key().pk_sig()))
+            .then_with(|| cmp_group(&a.compute_key().pr_sig(), &b.compute_key().pr_sig())),
+        (Literal::String(a), Literal::String(b)) => (**a).cmp(&**b),
+        (a, b) => a.variant().cmp(&b.variant()),
+    }
+}
+
+/// Returns the structural ordering of the given groups, by their affine coordinates.
+fn cmp_group<N: Network>(a: &Group<N>, b: &Group<N>) -> Ordering {
+    a.to_x_coordinate().cmp(&b.to_x_coordinate()).then_with(|| a.to_y_coordinate().cmp(&b.to_y_coordinate()))
+}
+
+/// Returns the structural ordering of the given records, by their owner, entries, and nonce.
+fn cmp_record<N: Network>(a: &Record<N, Plaintext<N>>, b: &Record<N, Plaintext<N>>) -> Ordering {
+    let owner = match (a.owner(), b.owner()) {
+        (Owner::Public(a), Owner::Public(b)) => cmp_group(a, b),
+        (Owner::Private(a), Owner::Private(b)) => cmp_plaintext(a, b),
+        (Owner::Public(..), Owner::Private(..)) => Ordering::Less,
+        (Owner::Private(..), Owner::Public(..)) => Ordering::Greater,
+    };
+    owner
+        .then_with(|| {
+            cmp_sequences(a.data().iter(), b.data().iter(), |(name_a, a), (name_b, b)| {
+                name_a.cmp(name_b).then_with(|| cmp_entry(a, b))
+            })
+        })
+        .then_with(|| cmp_group(a.nonce(), b.nonce()))
+}
+
+/// Returns the structural ordering of the given record entries.
+/// Constant entries are ordered before public entries, and public entries are ordered before private entries.
+fn cmp_entry<N: Network>(a: &Entry<N, Plaintext<N>>, b: &Entry<N, Plaintext<N>>) -> Ordering {
+    let rank = |entry: &Entry<N, Plaintext<N>>| match entry {
+        Entry::Constant(..) => 0,
+        Entry::Public(..) => 1,
+        Entry::Private(..) => 2,
+    };
+    match (a, b) {
+        (Entry::Constant(a), Entry::Constant(b))
+        | (Entry::Public(a), Entry::Public(b))
+        | (Entry::Private(a), Entry::Private(b)) => cmp_plaintext(a, b),
+        (a, b) => rank(a).cmp(&rank(b)),
+    }
+}
+
+/// Returns the structural ordering of the given futures, by their program ID, function name, and arguments.
+fn cmp_future<N: Network>(a: &Future<N>, b: &Future<N>) -> Ordering {
+    a.program_id()
+        .cmp(b.program_id())
+        .then_with(|| a.function_name().cmp(b.function_name()))
+        .then_with(|| cmp_sequences(a.arguments().iter(), b.arguments().iter(), cmp_argument))
+}
+
+/// Returns the structural ordering of the given future arguments. Plaintexts are ordered before futures.
+fn cmp_argument<N: Network>(a: &Argument<N>, b: &Argument<N>) -> Ordering {
+    match (a, b) {
+        (Argument::Plaintext(a), Argument::Plaintext(b)) => cmp_plaintext(a, b),
+        (Argument::Future(a), Argument::Future(b)) => cmp_future(a, b),
+        (Argument::Plaintext(..), Argument::Future(..)) => Ordering::Less,
+        (Argument::Future(..), Argument::Plaintext(..)) => Ordering::Greater,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    use std::collections::BTreeSet;
+
+    type CurrentNetwork = MainnetV0;
+
+    fn sample_values() -> Result<Vec<Value<CurrentNetwork>>> {
+        Ok(vec![
+            Value::from_str("{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, token_amount: 100u64.private, _nonce: 0group.public }")?,
+            Value::from_str("5u8")?,
+            Value::from_str("{ a: 1field, b: true }")?,
+            Value::from_str("{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, token_amount: 100u64.private, _nonce: 0group.public }")?,
+            Value::from_str("[1u32, 2u32, 3u32]")?,
+            Value::from_str("3u8")?,
+        ])
+    }
+
+    #[test]
+    fn test_value_ordering() -> Result<()> {
+        let values = sample_values()?;
+
+        // Sort the values.
+        let mut sorted = values.clone();
+        sorted.sort();
+
+        // Ensure all plaintext values are ordered before all record values.
+        let num_plaintexts = values.iter().filter(|value| matches!(value, Value::Plaintext(..))).count();
+        assert!(sorted[..num_plaintexts].iter().all(|value| matches!(value, Value::Plaintext(..))));
+        assert!(sorted[num_plaintexts..].iter().all(|value| matches!(value, Value::Record(..))));
+
+        // Ensure literals of the same type are ordered by their values.
+        let position = |value: &str| sorted.iter().position(|v| v == &Value::from_str(value).unwrap());
+        assert!(position("3u8") < position("5u8"));
+
+        // Ensure the sort order is stable, regardless of the initial order.
+        let mut reversed = values.iter().rev().cloned().collect::<Vec<_>>();
+        reversed.sort();
+        assert_eq!(sorted, reversed);
+
+        // Ensure the values can be stored in a sorted collection.
+        let set = values.iter().cloned().collect::<BTreeSet<_>>();
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), sorted);
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_ordering_is_consistent_with_eq() -> Result<()> {
+        let values = sample_values()?;
+        for a in &values {
+            for b in &values {
+                assert_eq!(a.cmp(b) == Ordering::Equal, a == b);
+                assert_eq!(a.cmp(b), b.cmp(a).reverse());
+            }
+            // Ensure an independently-constructed copy compares as equal.
+            assert_eq!(a.cmp(&Value::from_str(&a.to_string())?), Ordering::Equal);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns a hash of the contents of the value, where a record is hashed by its owner and entries only.
+    ///
+    /// As the record nonce is excluded, two records that differ only in their nonce have the same content hash.
+    /// This is intended for content-equality checks, and must not be used in place of a record commitment.
+    pub fn content_hash(&self) -> Result<Field<N>> {
+        let mut bits_le = vec![];
+        match self {
+            Self::Plaintext(plaintext) => {
+                // Tag the variant, to separate the domains of the hashes.
+                bits_le.extend([false, false]);
+                plaintext.write_bits_le(&mut bits_le);
+            }
+            Self::Record(record) => {
+                // Tag the variant, to separate the domains of the hashes.
+                bits_le.extend([true, false]);
+                // Compute the data bits.
+                let mut data_bits_le = vec![];
+                for (identifier, entry) in record.data() {
+                    identifier.write_bits_le(&mut data_bits_le);
+                    entry.write_bits_le(&mut data_bits_le);
+                }
+                // Construct the bits of the owner and the data, without the nonce.
+                record.owner().write_bits_le(&mut bits_le);
+                u32::try_from(data_bits_le.len())?.write_bits_le(&mut bits_le);
+                bits_le.extend_from_slice(&data_bits_le);
+            }
+            Self::Future(future) => {
+                // Tag the variant, to separate the domains of the hashes.
+                bits_le.extend([false, true]);
+                future.write_bits_le(&mut bits_le);
+            }
+        }
+        N::hash_bhp1024(&bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Identifier, ProgramID};
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_content_hash() -> Result<()> {
+        // Initialize two records that are identical, except for their nonce.
+        let record = |nonce: &str| {
+            Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+                "{{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 100u64.private, _nonce: {nonce}.public }}"
+            ))
+        };
+        let first = record("0group")?;
+        let second = record(&CurrentNetwork::g_powers()[0].to_string())?;
+
+        // Ensure the records have the same content hash.
+        assert_eq!(Value::from(&first).content_hash()?, Value::from(&second).content_hash()?);
+
+        // Ensure the records have different commitments.
+        let (program_id, record_name) = (ProgramID::from_str("token.aleo")?, Identifier::from_str("token")?);
+        assert_ne!(first.to_commitment(&program_id, &record_name)?, second.to_commitment(&program_id, &record_name)?);
+
+        // Ensure a record with different entries has a different content hash.
+        let third = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 101u64.private, _nonce: 0group.public }",
+        )?;
+        assert_ne!(Value::from(&first).content_hash()?, Value::from(&third).content_hash()?);
+
+        // Ensure plaintexts are hashed by their contents.
+        let plaintext = Value::<CurrentNetwork>::from_str("{ a: 1u8, b: 2u8 }")?;
+        assert_eq!(plaintext.content_hash()?, Value::from_str("{ a: 1u8, b: 2u8 }")?.content_hash()?);
+        assert_ne!(plaintext.content_hash()?, Value::from_str("{ a: 1u8, b: 3u8 }")?.content_hash()?);
+        Ok(())
+    }
+}
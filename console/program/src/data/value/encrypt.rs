@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Ciphertext;
+use snarkvm_console_account::ViewKey;
+
+impl<N: Network> Value<N> {
+    /// Encrypts the record value for its owner under the given randomizer.
+    /// This errors if the value is not a record, or if the randomizer does not correspond to the record nonce.
+    pub fn encrypt_record(&self, randomizer: Scalar<N>) -> Result<Record<N, Ciphertext<N>>> {
+        match self {
+            Self::Record(record) => record.encrypt(randomizer),
+            Self::Plaintext(..) => bail!("Cannot encrypt a plaintext value as a record"),
+            Self::Future(..) => bail!("Cannot encrypt a future value as a record"),
+        }
+    }
+
+    /// Decrypts the given record ciphertext with the view key of its owner, and returns it as a record value.
+    pub fn decrypt_record(ciphertext: &Record<N, Ciphertext<N>>, view_key: &ViewKey<N>) -> Result<Self> {
+        Ok(Self::Record(ciphertext.decrypt(view_key)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_encrypt_and_decrypt_record() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Initialize the owner.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Initialize a record value, with a nonce for the randomizer.
+        let randomizer = Scalar::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let value = Value::<CurrentNetwork>::from_str(&format!(
+            "{{ owner: {address}.private, amount: 100u64.private, memo: 5field.public, _nonce: {nonce}.public }}"
+        ))?;
+
+        // Ensure the record round trips.
+        let ciphertext = value.encrypt_record(randomizer)?;
+        assert_eq!(Value::decrypt_record(&ciphertext, &view_key)?, value);
+
+        // Ensure a mismatched randomizer is rejected.
+        assert!(value.encrypt_record(Scalar::rand(rng)).is_err());
+
+        // Ensure a plaintext value is rejected.
+        let plaintext = Value::<CurrentNetwork>::from_str("{ amount: 100u64 }")?;
+        assert!(plaintext.encrypt_record(randomizer).is_err());
+        Ok(())
+    }
+}
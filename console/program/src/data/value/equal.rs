@@ -46,3 +46,70 @@ impl<N: Network> Equal<Self> for Value<N> {
         }
     }
 }
+
+impl<N: Network> Value<N> {
+    /// Returns `true` if `self` and `other` are equal, ignoring the visibility of the record owner and entries.
+    pub fn eq_ignoring_visibility(&self, other: &Self) -> bool {
+        /// Returns the plaintext of the given entry, regardless of its visibility.
+        fn entry_plaintext<N: Network>(entry: &Entry<N, Plaintext<N>>) -> &Plaintext<N> {
+            match entry {
+                Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => plaintext,
+            }
+        }
+
+        match (self, other) {
+            (Self::Record(a), Self::Record(b)) => {
+                // Ensure the owner addresses, nonces, and entry counts are equal.
+                **a.owner() == **b.owner()
+                    && a.nonce() == b.nonce()
+                    && a.data().len() == b.data().len()
+                    // Ensure the entry names and plaintexts are equal.
+                    && a.data().iter().zip_eq(b.data().iter()).all(|((name_a, entry_a), (name_b, entry_b))| {
+                        name_a == name_b && entry_plaintext(entry_a) == entry_plaintext(entry_b)
+                    })
+            }
+            // Plaintexts and futures do not carry visibilities.
+            _ => self == other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_eq_ignoring_visibility() -> Result<()> {
+        // Returns a record value with the given visibilities.
+        let record = |owner: &str, amount: &str| {
+            Value::<CurrentNetwork>::from_str(&format!(
+                "{{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.{owner}, amount: 100u64.{amount}, _nonce: 0group.public }}"
+            ))
+        };
+
+        // Ensure records with the same data, but different visibilities, are equal only when ignoring visibility.
+        let first = record("private", "private")?;
+        let second = record("public", "public")?;
+        assert!(first.eq_ignoring_visibility(&second));
+        assert!(first != second);
+        let third = record("private", "constant")?;
+        assert!(first.eq_ignoring_visibility(&third));
+        assert!(first != third);
+
+        // Ensure records with different data are not equal.
+        let fourth = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, amount: 101u64.public, _nonce: 0group.public }",
+        )?;
+        assert!(!second.eq_ignoring_visibility(&fourth));
+
+        // Ensure plaintexts are compared as usual.
+        let plaintext = Value::<CurrentNetwork>::from_str("{ a: 1u8 }")?;
+        assert!(plaintext.eq_ignoring_visibility(&Value::from_str("{ a: 1u8 }")?));
+        assert!(!plaintext.eq_ignoring_visibility(&Value::from_str("{ a: 2u8 }")?));
+        assert!(!plaintext.eq_ignoring_visibility(&first));
+        Ok(())
+    }
+}
@@ -32,4 +32,42 @@ impl<N: Network> Value<N> {
             Self::Future(future) => Ok(future.find(path)?),
         }
     }
+
+    /// Returns the element at the given index, if the value is an array plaintext.
+    /// This errors if the value is not an array, or if the index is out of range.
+    pub fn get_index(&self, index: u32) -> Result<Self> {
+        match self {
+            Self::Plaintext(Plaintext::Array(elements, ..)) => match elements.get(index as usize) {
+                Some(element) => Ok(Self::Plaintext(element.clone())),
+                None => bail!("Index '{index}' is out of range for an array of length {}", elements.len()),
+            },
+            _ => bail!("Cannot index into a value that is not an array"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_get_index() -> Result<()> {
+        let value = Value::<CurrentNetwork>::from_str("[[1u8, 2u8], [3u8, 4u8], [5u8, 6u8]]")?;
+
+        // Ensure an in-range index returns the element.
+        assert_eq!(value.get_index(1)?, Value::from_str("[3u8, 4u8]")?);
+        assert_eq!(value.get_index(2)?.get_index(0)?, Value::from_str("5u8")?);
+
+        // Ensure an out-of-range index is rejected, with the length.
+        let error = value.get_index(3).unwrap_err().to_string();
+        assert!(error.contains("length 3"), "{error}");
+
+        // Ensure values that are not arrays are rejected.
+        assert!(Value::<CurrentNetwork>::from_str("1u8")?.get_index(0).is_err());
+        assert!(Value::<CurrentNetwork>::from_str("{ a: [1u8] }")?.get_index(0).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{Identifier, U32};
+
+impl<N: Network> Value<N> {
+    /// Returns the path and literal of the first leaf that satisfies the given predicate, if one exists.
+    /// Leaves are visited in order, and the traversal stops at the first match.
+    /// The owner of a record is visited as the `owner` leaf, before its entries; the nonce is not visited.
+    pub fn find_leaf(
+        &self,
+        predicate: impl Fn(&[Access<N>], &Literal<N>) -> bool,
+    ) -> Option<(Vec<Access<N>>, Literal<N>)> {
+        let mut path = Vec::new();
+        let literal = match self {
+            Self::Plaintext(plaintext) => find_in_plaintext(plaintext, &mut path, &predicate),
+            Self::Record(record) => {
+                // Visit the owner.
+                // Note: this unwrap is safe, as `owner` is a valid identifier.
+                path.push(Access::Member(Identifier::from_str("owner").unwrap()));
+                let owner = Literal::Address(**record.owner());
+                if predicate(&path, &owner) {
+                    return Some((path, owner));
+                }
+                path.pop();
+                // Visit the entries.
+                record.data().iter().find_map(|(name, entry)| {
+                    let plaintext = match entry {
+                        Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => plaintext,
+                    };
+                    path.push(Access::Member(*name));
+                    let literal = find_in_plaintext(plaintext, &mut path, &predicate);
+                    if literal.is_none() {
+                        path.pop();
+                    }
+                    literal
+                })
+            }
+            Self::Future(future) => find_in_future(future, &mut path, &predicate),
+        };
+        literal.map(|literal| (path, literal))
+    }
+}
+
+/// Returns the first literal in the given plaintext that satisfies the predicate.
+/// On a match, `path` is left as the path to the literal; otherwise, it is restored.
+fn find_in_plaintext<N: Network>(
+    plaintext: &Plaintext<N>,
+    path: &mut Vec<Access<N>>,
+    predicate: &impl Fn(&[Access<N>], &Literal<N>) -> bool,
+) -> Option<Literal<N>> {
+    match plaintext {
+        Plaintext::Literal(literal, ..) => predicate(path, literal).then(|| literal.clone()),
+        Plaintext::Struct(members, ..) => members.iter().find_map(|(name, member)| {
+            path.push(Access::Member(*name));
+            let literal = find_in_plaintext(member, path, predicate);
+            if literal.is_none() {
+                path.pop();
+            }
+            literal
+        }),
+        Plaintext::Array(elements, ..) => elements.iter().enumerate().find_map(|(index, element)| {
+            path.push(Access::Index(U32::new(index as u32)));
+            let literal = find_in_plaintext(element, path, predicate);
+            if literal.is_none() {
+                path.pop();
+            }
+            literal
+        }),
+    }
+}
+
+/// Returns the first literal in the arguments of the given future that satisfies the predicate.
+/// On a match, `path` is left as the path to the literal; otherwise, it is restored.
+fn find_in_future<N: Network>(
+    future: &Future<N>,
+    path: &mut Vec<Access<N>>,
+    predicate: &impl Fn(&[Access<N>], &Literal<N>) -> bool,
+) -> Option<Literal<N>> {
+    future.arguments().iter().enumerate().find_map(|(index, argument)| {
+        path.push(Access::Index(U32::new(index as u32)));
+        let literal = match argument {
+            Argument::Plaintext(plaintext) => find_in_plaintext(plaintext, path, predicate),
+            Argument::Future(future) => find_in_future(future, path, predicate),
+        };
+        if literal.is_none() {
+            path.pop();
+        }
+        literal
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    use core::cell::Cell;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ADDRESS: &str = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+
+    #[test]
+    fn test_find_leaf() -> Result<()> {
+        let member = |name: &str| Access::<CurrentNetwork>::Member(Identifier::from_str(name).unwrap());
+        let index = |index: u32| Access::<CurrentNetwork>::Index(U32::new(index));
+
+        // Initialize a record with an address entry between two runs of 10 entries.
+        let entries = |prefix: &str| (0..10).map(|i| format!("{prefix}{i}: {i}u64.private, ")).collect::<String>();
+        let record = Value::<CurrentNetwork>::from_str(&format!(
+            "{{ owner: {ADDRESS}.private, {}receiver: {ADDRESS}.private, {}_nonce: 0group.public }}",
+            entries("a"),
+            entries("b")
+        ))?;
+
+        // Find the first address leaf, which is the owner.
+        let calls = Cell::new(0);
+        let is_address = |_: &[Access<CurrentNetwork>], literal: &Literal<CurrentNetwork>| {
+            calls.set(calls.get() + 1);
+            matches!(literal, Literal::Address(..))
+        };
+        let (path, literal) = record.find_leaf(is_address).unwrap();
+        assert_eq!(path, vec![member("owner")]);
+        assert_eq!(literal, Literal::from_str(ADDRESS)?);
+        assert_eq!(calls.get(), 1);
+
+        // Find the first address leaf that is not the owner, and ensure the traversal stops there.
+        calls.set(0);
+        let (path, literal) =
+            record.find_leaf(|path, literal| is_address(path, literal) && path != [member("owner")]).unwrap();
+        assert_eq!(path, vec![member("receiver")]);
+        assert_eq!(literal, Literal::from_str(ADDRESS)?);
+        assert_eq!(calls.get(), 12);
+
+        // Find a nested leaf in a plaintext.
+        let value = Value::<CurrentNetwork>::from_str("{ a: 5u8, b: [{ c: 1u32 }, { c: 7u32 }], d: 7u32 }")?;
+        let (path, literal) = value.find_leaf(|_, literal| literal == &Literal::from_str("7u32").unwrap()).unwrap();
+        assert_eq!(path, vec![member("b"), index(1), member("c")]);
+        assert_eq!(literal, Literal::from_str("7u32")?);
+        assert_eq!(value.find(&path)?, Value::from_str("7u32")?);
+
+        // Ensure no leaf is found when none matches.
+        assert!(value.find_leaf(|_, literal| matches!(literal, Literal::Address(..))).is_none());
+        Ok(())
+    }
+}
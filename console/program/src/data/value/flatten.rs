@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns the leaves of every array in the value, flattened in row-major order.
+    /// Literals that are not contained in an array are skipped, while struct and record boundaries are ignored.
+    ///
+    /// This is intended for homogeneous numeric arrays, where the flattened leaves form a dense sequence.
+    pub fn flatten_array_leaves(&self) -> Vec<Literal<N>> {
+        let mut leaves = Vec::new();
+        match self {
+            Self::Plaintext(plaintext) => flatten_plaintext(plaintext, false, &mut leaves),
+            Self::Record(record) => {
+                for entry in record.data().values() {
+                    match entry {
+                        Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
+                            flatten_plaintext(plaintext, false, &mut leaves)
+                        }
+                    }
+                }
+            }
+            Self::Future(future) => flatten_future(future, &mut leaves),
+        }
+        leaves
+    }
+}
+
+/// Appends the array leaves of the given plaintext to `leaves`, where `in_array` indicates
+/// whether the plaintext is itself contained in an array.
+fn flatten_plaintext<N: Network>(plaintext: &Plaintext<N>, in_array: bool, leaves: &mut Vec<Literal<N>>) {
+    match plaintext {
+        Plaintext::Literal(literal, ..) => {
+            if in_array {
+                leaves.push(literal.clone());
+            }
+        }
+        Plaintext::Struct(members, ..) => {
+            members.values().for_each(|member| flatten_plaintext(member, in_array, leaves));
+        }
+        Plaintext::Array(elements, ..) => {
+            elements.iter().for_each(|element| flatten_plaintext(element, true, leaves));
+        }
+    }
+}
+
+/// Appends the array leaves of the arguments of the given future to `leaves`.
+fn flatten_future<N: Network>(future: &Future<N>, leaves: &mut Vec<Literal<N>>) {
+    for argument in future.arguments() {
+        match argument {
+            Argument::Plaintext(plaintext) => flatten_plaintext(plaintext, false, leaves),
+            Argument::Future(future) => flatten_future(future, leaves),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_flatten_array_leaves() -> Result<()> {
+        let literals =
+            |literals: &[&str]| literals.iter().map(|literal| Literal::from_str(literal)).collect::<Result<Vec<_>>>();
+
+        // Check a multi-dimensional array, which is flattened in row-major order.
+        let value = Value::<CurrentNetwork>::from_str("[[1u8, 2u8], [3u8, 4u8], [5u8, 6u8]]")?;
+        assert_eq!(value.flatten_array_leaves(), literals(&["1u8", "2u8", "3u8", "4u8", "5u8", "6u8"])?);
+
+        // Check that struct boundaries are ignored, and literals outside of arrays are skipped.
+        let value = Value::<CurrentNetwork>::from_str(
+            "{ a: 0u8, b: [1u8, 2u8], c: { d: [[3u8], [4u8]], e: 5u8 }, f: [{ g: 6u8 }, { g: 7u8 }] }",
+        )?;
+        assert_eq!(value.flatten_array_leaves(), literals(&["1u8", "2u8", "3u8", "4u8", "6u8", "7u8"])?);
+
+        // Check a record.
+        let value = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amounts: [1u64.private, 2u64.private], total: 3u64.public, _nonce: 0group.public }",
+        )?;
+        assert_eq!(value.flatten_array_leaves(), literals(&["1u64", "2u64"])?);
+
+        // Check a value without arrays.
+        assert!(Value::<CurrentNetwork>::from_str("{ a: 1u8 }")?.flatten_array_leaves().is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{Identifier, Owner};
+
+use serde_json::{json, Map, Value as JsonValue};
+
+impl<N: Network> Value<N> {
+    /// Returns the value as structured JSON, for interoperability with external tooling.
+    ///
+    /// Plaintext literals are encoded as strings with their type suffix (i.e. `"1u8"`), structs as
+    /// objects, and arrays as arrays. The value is tagged by its kind:
+    ///   - `{ "plaintext": .. }`
+    ///   - `{ "record": { "owner": { "visibility": .., "value": .. }, "entries": { .. }, "nonce": .. } }`
+    ///   - `{ "future": .. }`, where the future is encoded in its string form.
+    pub fn to_json(&self) -> JsonValue {
+        match self {
+            Self::Plaintext(plaintext) => json!({ "plaintext": plaintext_to_json(plaintext) }),
+            Self::Record(record) => {
+                // Encode the owner.
+                let owner = match record.owner() {
+                    Owner::Public(address) => json!({ "visibility": "public", "value": address.to_string() }),
+                    Owner::Private(plaintext) => {
+                        json!({ "visibility": "private", "value": plaintext_to_json(plaintext) })
+                    }
+                };
+                // Encode the entries.
+                let entries = record
+                    .data()
+                    .iter()
+                    .map(|(name, entry)| {
+                        let (visibility, plaintext) = match entry {
+                            Entry::Constant(plaintext) => ("constant", plaintext),
+                            Entry::Public(plaintext) => ("public", plaintext),
+                            Entry::Private(plaintext) => ("private", plaintext),
+                        };
+                        (name.to_string(), json!({ "visibility": visibility, "value": plaintext_to_json(plaintext) }))
+                    })
+                    .collect::<Map<_, _>>();
+                json!({ "record": { "owner": owner, "entries": entries, "nonce": record.nonce().to_string() } })
+            }
+            Self::Future(future) => json!({ "future": future.to_string() }),
+        }
+    }
+
+    /// Returns the value from its structured JSON form, as produced by `to_json`.
+    pub fn from_json(value: &JsonValue) -> Result<Self> {
+        // Retrieve the kind of the value.
+        let object = value.as_object().ok_or_else(|| anyhow!("Expected a JSON object for a value"))?;
+        ensure!(object.len() == 1, "Expected a JSON value with a single kind, found {} keys", object.len());
+        // Note: This unwrap is safe, as the object is checked to have exactly one key.
+        let (kind, value) = object.iter().next().unwrap();
+
+        match kind.as_str() {
+            "plaintext" => Ok(Self::Plaintext(plaintext_from_json(value, 0)?)),
+            "record" => {
+                // Retrieves the given field of the record.
+                let field = |name: &str| value.get(name).ok_or_else(|| anyhow!("Missing '{name}' in the JSON record"));
+                // Retrieves the visibility and the plaintext of the given JSON entry.
+                let entry = |entry: &JsonValue| -> Result<(String, Plaintext<N>)> {
+                    let visibility = entry.get("visibility").and_then(JsonValue::as_str);
+                    let plaintext = entry.get("value").ok_or_else(|| anyhow!("Missing 'value' in the JSON entry"))?;
+                    match visibility {
+                        Some(visibility) => Ok((visibility.to_string(), plaintext_from_json(plaintext, 0)?)),
+                        None => bail!("Missing 'visibility' in the JSON entry"),
+                    }
+                };
+
+                // Decode the owner.
+                let owner = match entry(field("owner")?)? {
+                    (visibility, Plaintext::Literal(Literal::Address(address), _)) => match visibility.as_str() {
+                        "public" => Owner::Public(address),
+                        "private" => Owner::Private(Plaintext::from(Literal::Address(address))),
+                        _ => bail!("Invalid visibility '{visibility}' for the record owner"),
+                    },
+                    (_, plaintext) => bail!("Expected an address for the record owner, found '{plaintext}'"),
+                };
+                // Decode the entries.
+                let entries = field("entries")?
+                    .as_object()
+                    .ok_or_else(|| anyhow!("Expected a JSON object for the record entries"))?
+                    .iter()
+                    .map(|(name, value)| {
+                        let (visibility, plaintext) = entry(value)?;
+                        let entry = match visibility.as_str() {
+                            "constant" => Entry::Constant(plaintext),
+                            "public" => Entry::Public(plaintext),
+                            "private" => Entry::Private(plaintext),
+                            _ => bail!("Invalid visibility '{visibility}' for the record entry '{name}'"),
+                        };
+                        Ok((Identifier::from_str(name)?, entry))
+                    })
+                    .collect::<Result<IndexMap<_, _>>>()?;
+                // Decode the nonce.
+                let nonce = match field("nonce")?.as_str() {
+                    Some(nonce) => Group::from_str(nonce)?,
+                    None => bail!("Expected a JSON string for the record nonce"),
+                };
+                Ok(Self::Record(Record::from_plaintext(owner, entries, nonce)?))
+            }
+            "future" => match value.as_str() {
+                Some(future) => Ok(Self::Future(Future::from_str(future)?)),
+                None => bail!("Expected a JSON string for the future"),
+            },
+            _ => bail!("Invalid value kind '{kind}' in JSON"),
+        }
+    }
+}
+
+/// Returns the given plaintext as structured JSON.
+fn plaintext_to_json<N: Network>(plaintext: &Plaintext<N>) -> JsonValue {
+    match plaintext {
+        Plaintext::Literal(literal, _) => JsonValue::String(literal.to_string()),
+        Plaintext::Struct(members, _) => JsonValue::Object(
+            members.iter().map(|(name, member)| (name.to_string(), plaintext_to_json(member))).collect(),
+        ),
+        Plaintext::Array(elements, _) => JsonValue::Array(elements.iter().map(plaintext_to_json).collect()),
+    }
+}
+
+/// Returns the plaintext from the given structured JSON, at the given depth.
+fn plaintext_from_json<N: Network>(value: &JsonValue, depth: usize) -> Result<Plaintext<N>> {
+    // Ensure the depth is within the bounds.
+    ensure!(depth <= N::MAX_DATA_DEPTH, "Plaintext exceeds maximum data depth of {}", N::MAX_DATA_DEPTH);
+    match value {
+        JsonValue::String(literal) => Ok(Plaintext::from(Literal::from_str(literal)?)),
+        JsonValue::Object(members) => {
+            let members = members
+                .iter()
+                .map(|(name, member)| Ok((Identifier::from_str(name)?, plaintext_from_json(member, depth + 1)?)))
+                .collect::<Result<IndexMap<_, _>>>()?;
+            Ok(Plaintext::Struct(members, Default::default()))
+        }
+        JsonValue::Array(elements) => {
+            let elements =
+                elements.iter().map(|element| plaintext_from_json(element, depth + 1)).collect::<Result<Vec<_>>>()?;
+            Ok(Plaintext::Array(elements, Default::default()))
+        }
+        _ => bail!("Expected a JSON string, object, or array for a plaintext, found '{value}'"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_json_round_trip() -> Result<()> {
+        // Check a nested struct plaintext.
+        let value = Value::<CurrentNetwork>::from_str(
+            "{ a: 1u8, b: { c: [2u16, 3u16], d: \"hello\" }, e: [{ f: true }, { f: false }] }",
+        )?;
+        let json = value.to_json();
+        assert_eq!(json["plaintext"]["a"], "1u8");
+        assert_eq!(json["plaintext"]["b"]["c"][1], "3u16");
+        assert_eq!(Value::from_json(&json)?, value);
+        // Ensure the JSON survives a round trip through its string form.
+        assert_eq!(Value::from_json(&serde_json::from_str(&json.to_string())?)?, value);
+
+        // Check a record, with entries of each visibility.
+        let value = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 100u64.private, \
+             points: [1u8.public, 2u8.public], data: { x: 3field.constant }, _nonce: 0group.public }",
+        )?;
+        let json = value.to_json();
+        assert_eq!(json["record"]["owner"]["visibility"], "private");
+        assert_eq!(json["record"]["entries"]["points"]["visibility"], "public");
+        assert_eq!(Value::from_json(&json)?, value);
+
+        // Check a record with a public owner.
+        let value = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.public, _nonce: 0group.public }",
+        )?;
+        assert_eq!(Value::from_json(&value.to_json())?, value);
+
+        // Check a future.
+        let value = Value::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer_public, arguments: [1u64, { a: 2u8 }] }",
+        )?;
+        assert_eq!(Value::from_json(&value.to_json())?, value);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_json_fails() {
+        // Ensure malformed JSON values are rejected.
+        for json in [
+            json!("1u8"),
+            json!({ "plaintext": 1 }),
+            json!({ "plaintext": "1u8", "future": "1u8" }),
+            json!({ "unknown": "1u8" }),
+            json!({ "record": { "owner": { "visibility": "private", "value": "1u8" }, "entries": {}, "nonce": "0group" } }),
+        ] {
+            assert!(Value::<CurrentNetwork>::from_json(&json).is_err());
+        }
+    }
+}
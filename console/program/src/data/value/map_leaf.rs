@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Identifier;
+
+impl<N: Network> Value<N> {
+    /// Returns a copy of the value, with the literal at the given path replaced by the output of `f` on that literal.
+    /// Record entries preserve their visibility, and the owner of a record cannot be mapped.
+    /// This method errors if the path does not lead to a literal, or if `f` errors.
+    pub fn map_leaf<A: Into<Access<N>> + Copy + Debug>(
+        &self,
+        path: &[A],
+        f: impl FnOnce(&Literal<N>) -> Result<Literal<N>>,
+    ) -> Result<Self> {
+        // Convert the path into accesses.
+        let path = path.iter().map(|access| (*access).into()).collect::<Vec<Access<N>>>();
+        match self {
+            Self::Plaintext(plaintext) => Ok(Self::Plaintext(map_plaintext_leaf(plaintext, &path, f)?)),
+            Self::Record(record) => {
+                // Retrieve the entry name.
+                let entry_name = match path.split_first() {
+                    Some((Access::Member(identifier), _)) => identifier,
+                    Some((Access::Index(_), _)) => bail!("Attempted to index into a record"),
+                    None => bail!("Attempted to map a leaf of a record without a path"),
+                };
+                // Ensure the owner is not mapped.
+                ensure!(*entry_name != Identifier::from_str("owner")?, "Cannot map the owner of a record");
+                // Map the leaf of the entry, preserving its visibility.
+                let mut data = record.data().clone();
+                let entry = match data.get(entry_name) {
+                    Some(Entry::Constant(plaintext)) => Entry::Constant(map_plaintext_leaf(plaintext, &path[1..], f)?),
+                    Some(Entry::Public(plaintext)) => Entry::Public(map_plaintext_leaf(plaintext, &path[1..], f)?),
+                    Some(Entry::Private(plaintext)) => Entry::Private(map_plaintext_leaf(plaintext, &path[1..], f)?),
+                    None => bail!("Record entry `{entry_name}` not found."),
+                };
+                data.insert(*entry_name, entry);
+                // Return the updated record.
+                Ok(Self::Record(Record::from_plaintext(record.owner().clone(), data, *record.nonce())?))
+            }
+            Self::Future(..) => bail!("Cannot map a leaf of a future"),
+        }
+    }
+}
+
+/// Returns a copy of the plaintext, with the literal at the given path replaced by the output of `f` on that literal.
+fn map_plaintext_leaf<N: Network>(
+    plaintext: &Plaintext<N>,
+    path: &[Access<N>],
+    f: impl FnOnce(&Literal<N>) -> Result<Literal<N>>,
+) -> Result<Plaintext<N>> {
+    match (plaintext, path.split_first()) {
+        // If the path is exhausted, map the literal.
+        (Plaintext::Literal(literal, ..), None) => Ok(Plaintext::from(f(literal)?)),
+        (Plaintext::Struct(..) | Plaintext::Array(..), None) => bail!("Attempted to map a non-literal value"),
+        // Otherwise, descend into the struct member.
+        (Plaintext::Struct(members, ..), Some((Access::Member(identifier), rest))) => {
+            let mut members = members.clone();
+            match members.get_mut(identifier) {
+                Some(member) => *member = map_plaintext_leaf(member, rest, f)?,
+                None => bail!("Failed to locate member '{identifier}'"),
+            }
+            Ok(Plaintext::Struct(members, Default::default()))
+        }
+        // Otherwise, descend into the array element.
+        (Plaintext::Array(elements, ..), Some((Access::Index(index), rest))) => {
+            let mut elements = elements.clone();
+            match elements.get_mut(**index as usize) {
+                Some(element) => *element = map_plaintext_leaf(element, rest, f)?,
+                None => bail!("Index '{index}' is out of bounds"),
+            }
+            Ok(Plaintext::Array(elements, Default::default()))
+        }
+        (_, Some((access, _))) => bail!("Invalid access '{access}' for the plaintext value"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::U32;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_map_leaf() -> Result<()> {
+        let value = Value::<CurrentNetwork>::from_str("{ a: 5u8, b: { c: [1u32, 2u32], d: true } }")?;
+        let member = |name: &str| Access::<CurrentNetwork>::Member(Identifier::from_str(name).unwrap());
+        let index = |index: u32| Access::<CurrentNetwork>::Index(U32::new(index));
+
+        // Ensure the closure receives the leaf at the path, and only that leaf is replaced.
+        let expected = Value::from_str("{ a: 5u8, b: { c: [1u32, 2u32], d: false } }")?;
+        let negated = value.map_leaf(&[member("b"), member("d")], |literal| {
+            assert_eq!(literal, &Literal::from_str("true")?);
+            Literal::from_str("false")
+        })?;
+        assert_eq!(negated, expected);
+
+        // Ensure an error from the closure is propagated.
+        assert!(value.map_leaf(&[member("b"), member("c"), index(0)], |_| bail!("Failed to map")).is_err());
+
+        // Ensure the closure is not called for an invalid path.
+        let mut calls = 0;
+        for path in [vec![member("b")], vec![member("e")], vec![member("b"), member("c"), index(2)]] {
+            assert!(value
+                .map_leaf(&path, |literal| {
+                    calls += 1;
+                    Ok(literal.clone())
+                })
+                .is_err());
+        }
+        assert_eq!(calls, 0);
+        Ok(())
+    }
+}
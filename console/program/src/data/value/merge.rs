@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{Identifier, Owner};
+
+use indexmap::IndexMap;
+
+impl<N: Network> Value<N> {
+    /// Returns a record that combines `self` and `other`, by summing their matching numeric entries.
+    /// Both values must be records with the same owner, entry names, visibilities, and literal types,
+    /// and every entry must be an integer, field, group, or scalar literal.
+    /// The owner and nonce of the merged record are taken from `self`.
+    pub fn merge_numeric(&self, other: &Value<N>) -> Result<Value<N>> {
+        // Ensure both values are records.
+        let (Self::Record(a), Self::Record(b)) = (self, other) else {
+            bail!("Cannot merge values that are not both records")
+        };
+        // Ensure the records have the same owner.
+        match (a.owner(), b.owner()) {
+            (Owner::Public(x), Owner::Public(y)) if x == y => (),
+            (Owner::Private(x), Owner::Private(y)) if x == y => (),
+            _ => bail!("Cannot merge records with different owners"),
+        }
+        // Ensure the records have the same entry names.
+        ensure!(a.data().keys().eq(b.data().keys()), "Cannot merge records with different entries");
+
+        // Sum the matching entries.
+        let data = a
+            .data()
+            .iter()
+            .zip_eq(b.data().values())
+            .map(|((name, x), y)| {
+                let entry = match (x, y) {
+                    (Entry::Constant(x), Entry::Constant(y)) => Entry::Constant(add_plaintexts(name, x, y)?),
+                    (Entry::Public(x), Entry::Public(y)) => Entry::Public(add_plaintexts(name, x, y)?),
+                    (Entry::Private(x), Entry::Private(y)) => Entry::Private(add_plaintexts(name, x, y)?),
+                    _ => bail!("Cannot merge entry '{name}', as its visibility differs between the records"),
+                };
+                Ok((*name, entry))
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+
+        Ok(Self::Record(Record::<N, Plaintext<N>>::from_plaintext(a.owner().clone(), data, *a.nonce())?))
+    }
+}
+
+/// Returns the sum of the given numeric literal plaintexts of the entry with the given name.
+fn add_plaintexts<N: Network>(name: &Identifier<N>, a: &Plaintext<N>, b: &Plaintext<N>) -> Result<Plaintext<N>> {
+    // Sums two integers, erroring on overflow.
+    macro_rules! checked_add {
+        ($variant:ident, $a:expr, $b:expr) => {
+            match (**$a).checked_add(**$b) {
+                Some(sum) => Literal::$variant($variant::new(sum)),
+                None => bail!("Cannot merge entry '{name}', as the sum overflows"),
+            }
+        };
+    }
+
+    let (Plaintext::Literal(a, ..), Plaintext::Literal(b, ..)) = (a, b) else {
+        bail!("Cannot merge entry '{name}', as it is not a numeric literal")
+    };
+    let sum = match (a, b) {
+        (Literal::Field(a), Literal::Field(b)) => Literal::Field(*a + *b),
+        (Literal::Group(a), Literal::Group(b)) => Literal::Group(*a + *b),
+        (Literal::Scalar(a), Literal::Scalar(b)) => Literal::Scalar(*a + *b),
+        (Literal::I8(a), Literal::I8(b)) => checked_add!(I8, a, b),
+        (Literal::I16(a), Literal::I16(b)) => checked_add!(I16, a, b),
+        (Literal::I32(a), Literal::I32(b)) => checked_add!(I32, a, b),
+        (Literal::I64(a), Literal::I64(b)) => checked_add!(I64, a, b),
+        (Literal::I128(a), Literal::I128(b)) => checked_add!(I128, a, b),
+        (Literal::U8(a), Literal::U8(b)) => checked_add!(U8, a, b),
+        (Literal::U16(a), Literal::U16(b)) => checked_add!(U16, a, b),
+        (Literal::U32(a), Literal::U32(b)) => checked_add!(U32, a, b),
+        (Literal::U64(a), Literal::U64(b)) => checked_add!(U64, a, b),
+        (Literal::U128(a), Literal::U128(b)) => checked_add!(U128, a, b),
+        (a, b) if a.to_type() != b.to_type() => bail!("Cannot merge entry '{name}', as its type differs"),
+        _ => bail!("Cannot merge entry '{name}', as it is not a numeric literal"),
+    };
+    Ok(Plaintext::from(sum))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const OWNER: &str = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+
+    /// Returns a record with the given owner and entries.
+    fn sample_record(owner: &str, entries: &str) -> Result<Value<CurrentNetwork>> {
+        Value::from_str(&format!("{{ owner: {owner}.private, {entries}, _nonce: 0group.public }}"))
+    }
+
+    #[test]
+    fn test_merge_numeric() -> Result<()> {
+        // Merge two records, summing each numeric entry.
+        let a = sample_record(OWNER, "amount: 100u64.private, weight: 2field.public, delta: -3i8.constant")?;
+        let b = sample_record(OWNER, "amount: 50u64.private, weight: 5field.public, delta: 1i8.constant")?;
+        let expected = sample_record(OWNER, "amount: 150u64.private, weight: 7field.public, delta: -2i8.constant")?;
+        assert_eq!(a.merge_numeric(&b)?, expected);
+
+        // Ensure an overflowing sum is rejected.
+        let c = sample_record(
+            OWNER,
+            "amount: 18446744073709551615u64.private, weight: 0field.public, delta: 0i8.constant",
+        )?;
+        assert!(a.merge_numeric(&c).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_numeric_mismatch() -> Result<()> {
+        let a = sample_record(OWNER, "amount: 100u64.private")?;
+
+        // Ensure records with a different entry type, name, or visibility are rejected.
+        assert!(a.merge_numeric(&sample_record(OWNER, "amount: 100u32.private")?).is_err());
+        assert!(a.merge_numeric(&sample_record(OWNER, "total: 100u64.private")?).is_err());
+        assert!(a.merge_numeric(&sample_record(OWNER, "amount: 100u64.public")?).is_err());
+        // Ensure a record with a different owner is rejected.
+        let owner = Address::<CurrentNetwork>::zero().to_string();
+        assert!(a.merge_numeric(&sample_record(&owner, "amount: 100u64.private")?).is_err());
+        // Ensure a value that is not a record is rejected.
+        assert!(a.merge_numeric(&Value::from_str("100u64")?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_numeric_non_numeric() -> Result<()> {
+        // Ensure boolean, address, and composite entries are rejected.
+        for entries in
+            ["flag: true.private", &format!("receiver: {OWNER}.private"), "amounts: [1u64.private, 2u64.private]"]
+        {
+            let a = sample_record(OWNER, entries)?;
+            assert!(a.merge_numeric(&a).is_err());
+        }
+        Ok(())
+    }
+}
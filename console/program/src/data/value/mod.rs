@@ -13,14 +13,28 @@
 // limitations under the License.
 
 mod bytes;
+mod cast_leaf;
+mod compare;
+mod content_hash;
+mod encrypt;
 mod equal;
 mod find;
+mod find_leaf;
+mod flatten;
+mod json;
+mod map_leaf;
+mod merge;
+mod num_leaves;
 mod parse;
+mod patch;
+mod redact;
 mod serialize;
 mod to_bits;
 mod to_fields;
+mod well_formed;
+mod zeroize;
 
-use crate::{Access, Argument, Entry, Future, Literal, Plaintext, Record};
+use crate::{Access, Argument, Entry, Future, Literal, Owner, Plaintext, Record};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
@@ -34,6 +48,24 @@ pub enum Value<N: Network> {
     Future(Future<N>),
 }
 
+impl<N: Network> Value<N> {
+    /// Returns the plaintext, if the value is a plaintext.
+    pub const fn as_plaintext(&self) -> Option<&Plaintext<N>> {
+        match self {
+            Self::Plaintext(plaintext) => Some(plaintext),
+            Self::Record(..) | Self::Future(..) => None,
+        }
+    }
+
+    /// Returns the record, if the value is a record.
+    pub const fn as_record(&self) -> Option<&Record<N, Plaintext<N>>> {
+        match self {
+            Self::Record(record) => Some(record),
+            Self::Plaintext(..) | Self::Future(..) => None,
+        }
+    }
+}
+
 impl<N: Network> From<Literal<N>> for Value<N> {
     /// Initializes the value from a literal.
     fn from(literal: Literal<N>) -> Self {
@@ -149,3 +181,28 @@ impl<N: Network> TryFrom<&str> for Value<N> {
         Self::from_str(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_as_plaintext_and_as_record() -> Result<()> {
+        // Ensure a plaintext value only yields the plaintext.
+        let value = Value::<CurrentNetwork>::from_str("{ a: 1u8, b: [2u8, 3u8] }")?;
+        assert_eq!(value.as_plaintext(), Some(&Plaintext::from_str("{ a: 1u8, b: [2u8, 3u8] }")?));
+        assert!(value.as_record().is_none());
+
+        // Ensure a record value only yields the record.
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, _nonce: 0group.public }",
+        )?;
+        let value = Value::from(&record);
+        assert_eq!(value.as_record(), Some(&record));
+        assert!(value.as_plaintext().is_none());
+        Ok(())
+    }
+}
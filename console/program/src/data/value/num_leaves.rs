@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns the number of leaf literals in the value, without constructing their paths.
+    /// This follows the traversal of `find_leaf`: the owner of a record counts as a leaf, while the nonce does not.
+    pub fn num_leaves(&self) -> usize {
+        match self {
+            Self::Plaintext(plaintext) => num_plaintext_leaves(plaintext),
+            Self::Record(record) => {
+                // Count the owner, followed by the entries.
+                1 + record
+                    .data()
+                    .values()
+                    .map(|entry| match entry {
+                        Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
+                            num_plaintext_leaves(plaintext)
+                        }
+                    })
+                    .sum::<usize>()
+            }
+            Self::Future(future) => num_future_leaves(future),
+        }
+    }
+}
+
+/// Returns the number of leaf literals in the given plaintext.
+fn num_plaintext_leaves<N: Network>(plaintext: &Plaintext<N>) -> usize {
+    match plaintext {
+        Plaintext::Literal(..) => 1,
+        Plaintext::Struct(members, ..) => members.values().map(num_plaintext_leaves).sum(),
+        Plaintext::Array(elements, ..) => elements.iter().map(num_plaintext_leaves).sum(),
+    }
+}
+
+/// Returns the number of leaf literals in the arguments of the given future.
+fn num_future_leaves<N: Network>(future: &Future<N>) -> usize {
+    future
+        .arguments()
+        .iter()
+        .map(|argument| match argument {
+            Argument::Plaintext(plaintext) => num_plaintext_leaves(plaintext),
+            Argument::Future(future) => num_future_leaves(future),
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    use core::cell::Cell;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Returns the number of leaves visited by `find_leaf`, when no leaf matches.
+    fn count_visited(value: &Value<CurrentNetwork>) -> usize {
+        let visited = Cell::new(0);
+        let result = value.find_leaf(|_, _| {
+            visited.set(visited.get() + 1);
+            false
+        });
+        assert!(result.is_none());
+        visited.get()
+    }
+
+    #[test]
+    fn test_num_leaves() -> Result<()> {
+        // Check a nested record, where the owner is a leaf and the nonce is not.
+        let record = Value::<CurrentNetwork>::from_str(
+            r"{
+    owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private,
+    amounts: [1u64.private, 2u64.private, 3u64.private],
+    token: {
+        id: 4field.public,
+        limits: [[5u8.public, 6u8.public], [7u8.public, 8u8.public]]
+    },
+    _nonce: 0group.public
+}",
+        )?;
+        assert_eq!(record.num_leaves(), 1 + 3 + 1 + 4);
+        assert_eq!(record.num_leaves(), count_visited(&record));
+
+        // Check a plaintext literal and a nested struct.
+        let literal = Value::<CurrentNetwork>::from_str("1u8")?;
+        assert_eq!(literal.num_leaves(), 1);
+        let plaintext = Value::<CurrentNetwork>::from_str("{ a: 1u8, b: { c: [2u8, 3u8], d: 4u8 } }")?;
+        assert_eq!(plaintext.num_leaves(), 4);
+        assert_eq!(plaintext.num_leaves(), count_visited(&plaintext));
+        Ok(())
+    }
+}
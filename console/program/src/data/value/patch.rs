@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Value<N> {
+    /// Returns a copy of the value, with the literal at the given path replaced by the given literal.
+    /// This method errors if the path does not lead to a literal,
+    /// or if the type of the given literal differs from the type of the existing literal.
+    pub fn patch<A: Into<Access<N>> + Copy + Debug>(&self, path: &[A], new_leaf: Literal<N>) -> Result<Self> {
+        self.map_leaf(path, |literal| {
+            ensure!(
+                literal.to_type() == new_leaf.to_type(),
+                "Cannot patch a '{}' leaf with a '{}' literal",
+                literal.to_type(),
+                new_leaf.to_type()
+            );
+            Ok(new_leaf)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Identifier, U32};
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_patch() -> Result<()> {
+        let value = Value::<CurrentNetwork>::from_str("{ a: 5u8, b: { c: [1u32, 2u32], d: true } }")?;
+        let member = |name: &str| Access::<CurrentNetwork>::Member(Identifier::from_str(name).unwrap());
+        let index = |index: u32| Access::<CurrentNetwork>::Index(U32::new(index));
+
+        // Patch a nested struct member.
+        let expected = Value::from_str("{ a: 5u8, b: { c: [1u32, 2u32], d: false } }")?;
+        assert_eq!(value.patch(&[member("b"), member("d")], Literal::from_str("false")?)?, expected);
+
+        // Patch an array element.
+        let expected = Value::from_str("{ a: 5u8, b: { c: [1u32, 7u32], d: true } }")?;
+        assert_eq!(value.patch(&[member("b"), member("c"), index(1)], Literal::from_str("7u32")?)?, expected);
+
+        // Patch a record entry, preserving its visibility.
+        let record = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 100u64.private, _nonce: 0group.public }",
+        )?;
+        let expected = Value::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 5u64.private, _nonce: 0group.public }",
+        )?;
+        assert_eq!(record.patch(&[member("amount")], Literal::from_str("5u64")?)?, expected);
+        assert!(record.patch(&[member("owner")], Literal::from_str("5u64")?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_patch_invalid() -> Result<()> {
+        let value = Value::<CurrentNetwork>::from_str("{ a: 5u8, b: [1u32, 2u32] }")?;
+        let member = |name: &str| Access::<CurrentNetwork>::Member(Identifier::from_str(name).unwrap());
+        let index = |index: u32| Access::<CurrentNetwork>::Index(U32::new(index));
+
+        // Ensure a literal of another type is rejected.
+        let error = value.patch(&[member("a")], Literal::from_str("5u16")?).unwrap_err().to_string();
+        assert!(error.contains("Cannot patch a 'u8' leaf with a 'u16' literal"), "{error}");
+
+        // Ensure invalid paths are rejected.
+        assert!(value.patch(&[member("b")], Literal::from_str("5u32")?).is_err());
+        assert!(value.patch(&[member("c")], Literal::from_str("5u32")?).is_err());
+        assert!(value.patch(&[member("b"), index(2)], Literal::from_str("5u32")?).is_err());
+        Ok(())
+    }
+}
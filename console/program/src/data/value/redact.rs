@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Owner;
+
+/// The placeholder that replaces every literal value.
+const REDACTED: &str = "***";
+
+impl<N: Network> Value<N> {
+    /// Returns a single-line representation of the value that is safe to log.
+    /// The structure of the value (member names, array lengths, record entries and their visibilities,
+    /// and future program IDs and function names) is preserved, while every literal is replaced by
+    /// `***` followed by its type, i.e. `***u64`.
+    pub fn redacted_string(&self) -> String {
+        match self {
+            Self::Plaintext(plaintext) => redact_plaintext(plaintext, ""),
+            Self::Record(record) => redact_record(record),
+            Self::Future(future) => redact_future(future),
+        }
+    }
+}
+
+/// Returns the redacted representation of the given plaintext,
+/// appending the given visibility suffix (i.e. `.private`) to each literal.
+fn redact_plaintext<N: Network>(plaintext: &Plaintext<N>, visibility: &str) -> String {
+    match plaintext {
+        Plaintext::Literal(literal, ..) => format!("{REDACTED}{}{visibility}", literal.to_type()),
+        Plaintext::Struct(members, ..) => {
+            let members =
+                members.iter().map(|(name, member)| format!("{name}: {}", redact_plaintext(member, visibility)));
+            format!("{{ {} }}", members.collect::<Vec<_>>().join(", "))
+        }
+        Plaintext::Array(elements, ..) => {
+            let elements = elements.iter().map(|element| redact_plaintext(element, visibility));
+            format!("[{}]", elements.collect::<Vec<_>>().join(", "))
+        }
+    }
+}
+
+/// Returns the redacted representation of the given record.
+fn redact_record<N: Network>(record: &Record<N, Plaintext<N>>) -> String {
+    // Redact the owner.
+    let owner = match record.owner() {
+        Owner::Public(..) => format!("owner: {REDACTED}address.public"),
+        Owner::Private(..) => format!("owner: {REDACTED}address.private"),
+    };
+    // Redact the entries.
+    let entries = record.data().iter().map(|(name, entry)| match entry {
+        Entry::Constant(plaintext) => format!("{name}: {}", redact_plaintext(plaintext, ".constant")),
+        Entry::Public(plaintext) => format!("{name}: {}", redact_plaintext(plaintext, ".public")),
+        Entry::Private(plaintext) => format!("{name}: {}", redact_plaintext(plaintext, ".private")),
+    });
+    // Redact the nonce.
+    let nonce = format!("_nonce: {REDACTED}group.public");
+    format!(
+        "{{ {} }}",
+        std::iter::once(owner).chain(entries).chain(std::iter::once(nonce)).collect::<Vec<_>>().join(", ")
+    )
+}
+
+/// Returns the redacted representation of the given future.
+fn redact_future<N: Network>(future: &Future<N>) -> String {
+    let arguments = future.arguments().iter().map(|argument| match argument {
+        Argument::Plaintext(plaintext) => redact_plaintext(plaintext, ""),
+        Argument::Future(future) => redact_future(future),
+    });
+    format!(
+        "{{ program_id: {}, function_name: {}, arguments: [{}] }}",
+        future.program_id(),
+        future.function_name(),
+        arguments.collect::<Vec<_>>().join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_redacted_string() -> Result<()> {
+        // Check a literal.
+        let value = Value::<CurrentNetwork>::from_str("100u64")?;
+        assert_eq!(value.redacted_string(), "***u64");
+
+        // Check a nested plaintext.
+        let value = Value::<CurrentNetwork>::from_str("{ a: [1u8, 2u8, 3u8], b: { c: true } }")?;
+        assert_eq!(value.redacted_string(), "{ a: [***u8, ***u8, ***u8], b: { c: ***boolean } }");
+
+        // Check a nested record.
+        let record = r"{
+  owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private,
+  amount: 123456789u64.public,
+  data: { secret: 987654321field.private, flags: [true.private, false.private] },
+  _nonce: 0group.public
+}";
+        let value = Value::<CurrentNetwork>::from_str(record)?;
+        let redacted = value.redacted_string();
+        assert_eq!(
+            redacted,
+            "{ owner: ***address.private, amount: ***u64.public, data: { secret: ***field.private, flags: [***boolean.private, ***boolean.private] }, _nonce: ***group.public }"
+        );
+        // Ensure none of the literal values are present.
+        for secret in
+            ["aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah", "123456789", "987654321", "true"]
+        {
+            assert!(!redacted.contains(secret));
+        }
+
+        // Check a future.
+        let value = Value::<CurrentNetwork>::from_str(
+            "{ program_id: credits.aleo, function_name: transfer_public, arguments: [aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, 100u64] }",
+        )?;
+        assert_eq!(
+            value.redacted_string(),
+            "{ program_id: credits.aleo, function_name: transfer_public, arguments: [***address, ***u64] }"
+        );
+        Ok(())
+    }
+}
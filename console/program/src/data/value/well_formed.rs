@@ -0,0 +1,223 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{EntryType, Identifier, Owner, PlaintextType, RecordType, StructType, ValueType};
+
+impl<N: Network> Value<N> {
+    /// Checks that the value conforms to the given value type, erroring at the first divergence with its path.
+    /// This checks the shape of the value, i.e. the literal types, the struct members and their order,
+    /// the array lengths, and the record entries and their visibilities, resolving definitions by name.
+    ///
+    /// Note: External records are defined in another program, and must be checked against that program's
+    /// definitions, as a `ValueType::Record`.
+    pub fn is_well_formed<'a>(
+        &self,
+        value_type: &ValueType<N>,
+        get_struct: &impl Fn(&Identifier<N>) -> Result<&'a StructType<N>>,
+        get_record: &impl Fn(&Identifier<N>) -> Result<&'a RecordType<N>>,
+    ) -> Result<()> {
+        match (self, value_type) {
+            (
+                Self::Plaintext(plaintext),
+                ValueType::Constant(plaintext_type)
+                | ValueType::Public(plaintext_type)
+                | ValueType::Private(plaintext_type),
+            ) => check_plaintext(plaintext, plaintext_type, "value", get_struct),
+            (Self::Record(record), ValueType::Record(record_name)) => {
+                let record_type = get_record(record_name)?;
+                // Ensure the owner has the declared visibility.
+                match (record.owner(), record_type.owner().is_public()) {
+                    (Owner::Public(..), true) | (Owner::Private(..), false) => (),
+                    _ => bail!("The owner of record '{record_name}' at 'value.owner' has the wrong visibility"),
+                }
+                // Ensure the entries match the declared entries, in order.
+                for (entry_name, entry_type) in record_type.entries() {
+                    let path = format!("value.{entry_name}");
+                    let plaintext = match (record.data().get(entry_name), entry_type) {
+                        (Some(Entry::Constant(plaintext)), EntryType::Constant(..))
+                        | (Some(Entry::Public(plaintext)), EntryType::Public(..))
+                        | (Some(Entry::Private(plaintext)), EntryType::Private(..)) => plaintext,
+                        (Some(..), _) => {
+                            bail!("The entry at '{path}' does not have the declared visibility of '{entry_type}'")
+                        }
+                        (None, _) => bail!("Missing entry '{entry_name}' of record '{record_name}' at '{path}'"),
+                    };
+                    check_plaintext(plaintext, entry_type.plaintext_type(), &path, get_struct)?;
+                }
+                ensure_same_names(record.data().keys(), record_type.entries().keys(), "value", record_name)
+            }
+            (Self::Future(future), ValueType::Future(locator)) => {
+                ensure!(
+                    future.program_id() == locator.program_id() && future.function_name() == locator.resource(),
+                    "Expected a future of '{locator}' at 'value', found a future of '{}/{}'",
+                    future.program_id(),
+                    future.function_name()
+                );
+                Ok(())
+            }
+            (_, ValueType::ExternalRecord(locator)) => {
+                bail!("External record '{locator}' must be checked against the definitions of its program")
+            }
+            _ => bail!("Expected a '{value_type}' at 'value', found a {}", value_kind(self)),
+        }
+    }
+}
+
+/// Checks that the given plaintext at the given path conforms to the given plaintext type.
+fn check_plaintext<'a, N: Network>(
+    plaintext: &Plaintext<N>,
+    plaintext_type: &PlaintextType<N>,
+    path: &str,
+    get_struct: &impl Fn(&Identifier<N>) -> Result<&'a StructType<N>>,
+) -> Result<()> {
+    match (plaintext, plaintext_type) {
+        (Plaintext::Literal(literal, ..), PlaintextType::Literal(literal_type)) => {
+            ensure!(
+                literal.to_type() == *literal_type,
+                "Expected a '{literal_type}' at '{path}', found a '{}'",
+                literal.to_type()
+            );
+            Ok(())
+        }
+        (Plaintext::Struct(members, ..), PlaintextType::Struct(struct_name)) => {
+            let struct_type = get_struct(struct_name)?;
+            // Ensure each declared member is present, and well-formed.
+            for (member_name, member_type) in struct_type.members() {
+                let path = format!("{path}.{member_name}");
+                match members.get(member_name) {
+                    Some(member) => check_plaintext(member, member_type, &path, get_struct)?,
+                    None => bail!("Missing member '{member_name}' of struct '{struct_name}' at '{path}'"),
+                }
+            }
+            ensure_same_names(members.keys(), struct_type.members().keys(), path, struct_name)
+        }
+        (Plaintext::Array(elements, ..), PlaintextType::Array(array_type)) => {
+            ensure!(
+                elements.len() as u64 == u64::from(**array_type.length()),
+                "Expected {} elements at '{path}', found {}",
+                array_type.length(),
+                elements.len()
+            );
+            for (index, element) in elements.iter().enumerate() {
+                check_plaintext(element, array_type.next_element_type(), &format!("{path}[{index}]"), get_struct)?;
+            }
+            Ok(())
+        }
+        (Plaintext::Literal(..), _) => bail!("Expected a '{plaintext_type}' at '{path}', found a literal"),
+        (Plaintext::Struct(..), _) => bail!("Expected a '{plaintext_type}' at '{path}', found a struct"),
+        (Plaintext::Array(..), _) => bail!("Expected a '{plaintext_type}' at '{path}', found an array"),
+    }
+}
+
+/// Ensures the given names match the declared names of the given definition, including their order.
+/// This is checked after every declared name is found, so a divergence here is an unexpected or reordered name.
+fn ensure_same_names<'a, N: Network>(
+    names: impl Iterator<Item = &'a Identifier<N>>,
+    declared: impl Iterator<Item = &'a Identifier<N>>,
+    path: &str,
+    definition: &Identifier<N>,
+) -> Result<()> {
+    let (names, declared) = (names.collect::<Vec<_>>(), declared.collect::<Vec<_>>());
+    // Ensure there are no unexpected names.
+    if let Some(name) = names.iter().find(|name| !declared.contains(name)) {
+        bail!("Unexpected '{name}' in '{definition}' at '{path}'")
+    }
+    // Ensure the names are in the declared order.
+    ensure!(names == declared, "The members at '{path}' are not in the declared order of '{definition}'");
+    Ok(())
+}
+
+/// Returns the kind of the given value, for use in error messages.
+fn value_kind<N: Network>(value: &Value<N>) -> &'static str {
+    match value {
+        Value::Plaintext(..) => "plaintext",
+        Value::Record(..) => "record",
+        Value::Future(..) => "future",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_types::test_helpers::struct_resolver;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const OWNER: &str = "aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah";
+
+    /// Checks the given value against the given value type, resolving the `point` struct and `token` record.
+    fn check(value: &str, value_type: &str) -> Result<()> {
+        let structs = [StructType::<CurrentNetwork>::from_str("struct point:\n    x as u8;\n    ys as [u16; 2u32];")?];
+        let token = RecordType::<CurrentNetwork>::from_str(
+            "record token:\n    owner as address.private;\n    amount as u64.private;\n    origin as point.public;",
+        )?;
+        let get_struct = struct_resolver(&structs);
+        let get_record = |name: &Identifier<CurrentNetwork>| match name == token.name() {
+            true => Ok(&token),
+            false => bail!("Record '{name}' is not defined"),
+        };
+        Value::<CurrentNetwork>::from_str(value)?.is_well_formed(
+            &ValueType::from_str(value_type)?,
+            &get_struct,
+            &get_record,
+        )
+    }
+
+    #[test]
+    fn test_is_well_formed() -> Result<()> {
+        // Check conforming plaintexts.
+        check("5u8", "u8.public")?;
+        check("{ x: 1u8, ys: [2u16, 3u16] }", "point.private")?;
+        check("[{ x: 1u8, ys: [2u16, 3u16] }]", "[point; 1u32].constant")?;
+
+        // Check a conforming record.
+        check(
+            &format!(
+                "{{ owner: {OWNER}.private, amount: 1u64.private, origin: {{ x: 1u8.public, ys: [2u16.public, 3u16.public] }}, _nonce: 0group.public }}"
+            ),
+            "token.record",
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_well_formed_divergence() -> Result<()> {
+        // Returns the error message of the check.
+        let error = |value: &str, value_type: &str| check(value, value_type).unwrap_err().to_string();
+
+        // Ensure a missing struct member is reported with its path.
+        let message = error("[{ x: 1u8, ys: [2u16, 3u16] }, { x: 1u8 }]", "[point; 2u32].public");
+        assert!(message.contains("Missing member 'ys' of struct 'point' at 'value[1].ys'"), "{message}");
+        // Ensure an unexpected struct member is reported.
+        let message = error("{ x: 1u8, ys: [2u16, 3u16], z: 4u8 }", "point.public");
+        assert!(message.contains("Unexpected 'z'"), "{message}");
+        // Ensure a wrong array length and literal type are reported with their paths.
+        let message = error("{ x: 1u8, ys: [2u16] }", "point.public");
+        assert!(message.contains("Expected 2 elements at 'value.ys'"), "{message}");
+        let message = error("{ x: 1u8, ys: [2u16, 3u32] }", "point.public");
+        assert!(message.contains("Expected a 'u16' at 'value.ys[1]'"), "{message}");
+
+        // Ensure a record entry with the wrong visibility is reported.
+        let record = format!(
+            "{{ owner: {OWNER}.private, amount: 1u64.public, origin: {{ x: 1u8.public, ys: [2u16.public, 3u16.public] }}, _nonce: 0group.public }}"
+        );
+        let message = error(&record, "token.record");
+        assert!(message.contains("'value.amount'"), "{message}");
+        // Ensure a value of the wrong kind is rejected.
+        assert!(check("5u8", "token.record").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use ::zeroize::Zeroize;
+
+impl<N: Network> Zeroize for Value<N> {
+    /// Overwrites every literal in the value with the zero value of its type, so that secret values can be wiped from memory.
+    /// The structure of the value is preserved.
+    fn zeroize(&mut self) {
+        match self {
+            Self::Plaintext(plaintext) => plaintext.zeroize(),
+            Self::Record(record) => record.zeroize(),
+            Self::Future(future) => future.zeroize(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Identifier;
+    use snarkvm_console_network::MainnetV0;
+    use snarkvm_console_types::Address;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_zeroize() -> Result<()> {
+        // Zeroize a plaintext with nested containers.
+        let mut value = Value::<CurrentNetwork>::from_str(
+            r#"{ a: 5u8, b: [{ c: 7field, d: true }, { c: 9field, d: false }], e: "secret", f: -3i128 }"#,
+        )?;
+        // Populate the cached bits, to ensure they are cleared.
+        let bits = value.to_bits_le();
+        value.zeroize();
+        let expected =
+            Value::from_str(r#"{ a: 0u8, b: [{ c: 0field, d: false }, { c: 0field, d: false }], e: "", f: 0i128 }"#)?;
+        assert_eq!(value, expected);
+        assert_eq!(value.to_bits_le(), expected.to_bits_le());
+        assert_ne!(value.to_bits_le(), bits);
+
+        // Zeroize a record, preserving its entry names and visibilities.
+        let mut record = Value::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, amount: 100u64.private, memo: [1u8.public, 2u8.public], _nonce: 0group.public }",
+        )?;
+        record.zeroize();
+        let expected = Value::from_str(&format!(
+            "{{ owner: {}.private, amount: 0u64.private, memo: [0u8.public, 0u8.public], _nonce: 0group.public }}",
+            Address::<CurrentNetwork>::zero()
+        ))?;
+        assert_eq!(record, expected);
+        assert_eq!(record.to_bits_le(), expected.to_bits_le());
+
+        // Ensure the observable serialized literals are all zeros.
+        let Value::Record(record) = record else { unreachable!() };
+        let amount = record.find(&[Access::Member(Identifier::from_str("amount")?)])?;
+        let Entry::Private(Plaintext::Literal(amount, ..)) = amount else { unreachable!() };
+        assert!(amount.to_bytes_le()?[2..].iter().all(|byte| *byte == 0));
+        Ok(())
+    }
+}
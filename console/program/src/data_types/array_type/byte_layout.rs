@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{Identifier, StructType};
+
+impl<N: Network> ArrayType<N> {
+    /// Returns the byte size of each element of the array, in row-major order.
+    ///
+    /// Nested arrays are flattened into their innermost elements, and each struct element is sized as
+    /// the packed sum of its members. Struct definitions are resolved using the given `get_struct` function.
+    pub fn byte_layout<'a>(
+        &self,
+        get_struct: &impl Fn(&Identifier<N>) -> Result<&'a StructType<N>>,
+    ) -> Result<Vec<usize>> {
+        // Compute the layout of a single element.
+        let element_layout = match self.next_element_type() {
+            PlaintextType::Array(array_type) => array_type.byte_layout(get_struct)?,
+            element_type => vec![element_type.size_in_bytes(get_struct)?],
+        };
+        // Repeat the element layout for each element in the array.
+        Ok(element_layout.repeat(**self.length() as usize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_types::test_helpers::struct_resolver;
+    use snarkvm_console_network::MainnetV0;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_byte_layout() -> Result<()> {
+        // Initialize the structs.
+        let structs = [StructType::<CurrentNetwork>::from_str(
+            "struct foo:\n    a as u8;\n    b as u64;\n    c as [u16; 2u32];",
+        )?];
+        let get_struct = struct_resolver(&structs);
+
+        // Check a literal array.
+        let array = ArrayType::<CurrentNetwork>::from_str("[u8; 4u32]")?;
+        assert_eq!(array.byte_layout(&get_struct)?, vec![1, 1, 1, 1]);
+
+        // Check a multi-dimensional array.
+        let array = ArrayType::<CurrentNetwork>::from_str("[[u16; 2u32]; 3u32]")?;
+        assert_eq!(array.byte_layout(&get_struct)?, vec![2; 6]);
+
+        // Check a struct array.
+        let array = ArrayType::<CurrentNetwork>::from_str("[foo; 3u32]")?;
+        assert_eq!(array.byte_layout(&get_struct)?, vec![13, 13, 13]);
+
+        // Check an array of an undefined struct.
+        let array = ArrayType::<CurrentNetwork>::from_str("[bar; 2u32]")?;
+        assert!(array.byte_layout(&get_struct).is_err());
+
+        Ok(())
+    }
+}
@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod byte_layout;
 mod bytes;
 mod parse;
 pub(crate) mod serialize;
+mod size_in_bits;
+mod validate;
 
-use crate::{PlaintextType, U32};
+use crate::{LiteralType, PlaintextType, U32};
 use snarkvm_console_network::prelude::*;
 
 use core::fmt::{Debug, Display};
@@ -64,6 +67,16 @@ impl<N: Network> ArrayType<N> {
         &self.element_type
     }
 
+    /// Returns the inner array type, if the element type is itself an array.
+    /// In the case of a one-dimensional array, this will return `None`.
+    /// In the case of a multi-dimensional array, this will return the array type with the **outermost** dimension removed.
+    pub fn inner(&self) -> Option<&ArrayType<N>> {
+        match self.next_element_type() {
+            PlaintextType::Array(array_type) => Some(array_type),
+            _ => None,
+        }
+    }
+
     /// Returns the base element type.
     /// In the case of a one-dimensional array, this will return the element type of the array.
     /// In the case of a multi-dimensional array, this will return the element type of the **innermost** array.
@@ -85,6 +98,71 @@ impl<N: Network> ArrayType<N> {
     pub const fn length(&self) -> &U32<N> {
         &self.length
     }
+
+    /// Returns `true` if the array types are compatible for element-wise operations.
+    /// This holds when both arrays have the same number of dimensions and the same base element type,
+    /// and, in each dimension, the lengths are equal or one of them is 1, in which case it is broadcast.
+    pub fn is_broadcast_compatible(&self, other: &ArrayType<N>) -> bool {
+        let (mut this, mut other) = (self, other);
+        loop {
+            // Ensure the lengths of this dimension are equal, or that one of them can be broadcast.
+            if this.length != other.length && *this.length != 1 && *other.length != 1 {
+                return false;
+            }
+            match (this.inner(), other.inner()) {
+                // Proceed to the next dimension.
+                (Some(this_inner), Some(other_inner)) => (this, other) = (this_inner, other_inner),
+                // Ensure both arrays end at this dimension, with the same element type.
+                (None, None) => return this.element_type == other.element_type,
+                // The arrays have a different number of dimensions.
+                _ => return false,
+            }
+        }
+    }
+
+    /// Returns a new array type with the same dimensions, and the base element type replaced by the given type.
+    pub fn map_element_type(&self, new_element: PlaintextType<N>) -> Result<ArrayType<N>> {
+        // Ensure the new element type does not change the number of dimensions.
+        ensure!(
+            !matches!(new_element, PlaintextType::Array(..)),
+            "Cannot substitute the element type of '{self}' with the array type '{new_element}'"
+        );
+        // Collect the dimensions, from the outermost to the innermost.
+        let mut dimensions = vec![self.length];
+        let mut array_type = self;
+        while let Some(inner) = array_type.inner() {
+            dimensions.push(inner.length);
+            array_type = inner;
+        }
+        // Construct the new array type.
+        Self::new(new_element, dimensions)
+    }
+
+    /// Returns `true` if the base element type is an integer or a scalar literal type.
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self.base_element_type(),
+            PlaintextType::Literal(
+                LiteralType::I8
+                    | LiteralType::I16
+                    | LiteralType::I32
+                    | LiteralType::I64
+                    | LiteralType::I128
+                    | LiteralType::U8
+                    | LiteralType::U16
+                    | LiteralType::U32
+                    | LiteralType::U64
+                    | LiteralType::U128
+                    | LiteralType::Scalar
+            )
+        )
+    }
+
+    /// Returns the length of the **outermost** dimension, and the inner array type, if any.
+    /// In the case of a one-dimensional array, the inner array type is `None`.
+    pub fn split_first(&self) -> (u64, Option<ArrayType<N>>) {
+        (u64::from(*self.length), self.inner().cloned())
+    }
 }
 
 #[cfg(test)]
@@ -157,6 +235,61 @@ mod tests {
         assert_eq!(array.length(), &U32::new(3));
         assert!(!array.is_empty());
 
+        // Test peeling the dimensions of a multi-dimensional array type.
+        let array = ArrayType::<CurrentNetwork>::from_str("[[u8; 4u32]; 3u32]")?;
+        let inner = array.inner().unwrap();
+        assert_eq!(inner, &ArrayType::<CurrentNetwork>::from_str("[u8; 4u32]")?);
+        assert_eq!(inner.inner(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_broadcast_compatible() -> Result<()> {
+        let check = |a: &str, b: &str| -> Result<bool> {
+            let (a, b) = (ArrayType::<CurrentNetwork>::from_str(a)?, ArrayType::<CurrentNetwork>::from_str(b)?);
+            // Ensure compatibility is symmetric.
+            assert_eq!(a.is_broadcast_compatible(&b), b.is_broadcast_compatible(&a));
+            Ok(a.is_broadcast_compatible(&b))
+        };
+
+        // Test equal shapes.
+        assert!(check("[u8; 4u32]", "[u8; 4u32]")?);
+        assert!(check("[[field; 2u32]; 3u32]", "[[field; 2u32]; 3u32]")?);
+        assert!(check("[foo; 2u32]", "[foo; 2u32]")?);
+
+        // Test broadcastable length-1 dimensions.
+        assert!(check("[u8; 1u32]", "[u8; 4u32]")?);
+        assert!(check("[[field; 1u32]; 3u32]", "[[field; 2u32]; 3u32]")?);
+        assert!(check("[[field; 2u32]; 1u32]", "[[field; 1u32]; 3u32]")?);
+
+        // Test incompatible shapes.
+        assert!(!check("[u8; 2u32]", "[u8; 4u32]")?);
+        assert!(!check("[[field; 2u32]; 3u32]", "[[field; 3u32]; 3u32]")?);
+        assert!(!check("[[u8; 4u32]; 1u32]", "[u8; 4u32]")?);
+        // Test incompatible element types.
+        assert!(!check("[u8; 4u32]", "[u16; 4u32]")?);
+        assert!(!check("[foo; 1u32]", "[bar; 1u32]")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_element_type() -> Result<()> {
+        // Test substituting the base type of a one-dimensional array.
+        let array = ArrayType::<CurrentNetwork>::from_str("[u8; 4u32]")?;
+        let mapped = array.map_element_type(PlaintextType::from_str("u16")?)?;
+        assert_eq!(mapped, ArrayType::<CurrentNetwork>::from_str("[u16; 4u32]")?);
+
+        // Test substituting the base type of a multi-dimensional array, preserving the dimensions.
+        let array = ArrayType::<CurrentNetwork>::from_str("[[[u8; 2u32]; 3u32]; 4u32]")?;
+        let mapped = array.map_element_type(PlaintextType::from_str("foo")?)?;
+        assert_eq!(mapped, ArrayType::<CurrentNetwork>::from_str("[[[foo; 2u32]; 3u32]; 4u32]")?);
+        assert_eq!(mapped.length(), array.length());
+        assert_eq!(mapped.base_element_type(), &PlaintextType::Struct(Identifier::from_str("foo")?));
+
+        // Ensure substituting an array type is rejected.
+        assert!(array.map_element_type(PlaintextType::from_str("[u8; 2u32]")?).is_err());
         Ok(())
     }
 
@@ -174,4 +307,49 @@ mod tests {
         let type_ = ArrayType::<CurrentNetwork>::from_str("[foo; 1u8]");
         assert!(type_.is_err());
     }
+
+    #[test]
+    fn test_array_type_additional_dimension_fails() {
+        // Ensure a second length in the same brackets is reported as an additional dimension.
+        let error = ArrayType::<CurrentNetwork>::from_str("[u8; 3u32 4u32]").unwrap_err();
+        assert!(error.to_string().contains("unexpected additional dimension; use nested brackets"));
+        let error = ArrayType::<CurrentNetwork>::from_str("[[u8; 3u32 4u32]; 2u32]").unwrap_err();
+        assert!(error.to_string().contains("unexpected additional dimension; use nested brackets"));
+
+        let error = ArrayType::<CurrentNetwork>::from_str("[u8; 3 4]").unwrap_err();
+        assert!(error.to_string().contains("unexpected additional dimension; use nested brackets"));
+
+        // Ensure a single length without a type suffix is rejected without the hint.
+        let error = ArrayType::<CurrentNetwork>::from_str("[u8; 3]").unwrap_err();
+        assert!(!error.to_string().contains("unexpected additional dimension"));
+
+        // Ensure the nested form is accepted.
+        assert!(ArrayType::<CurrentNetwork>::from_str("[[u8; 4u32]; 3u32]").is_ok());
+    }
+
+    #[test]
+    fn test_split_first() -> Result<()> {
+        // Ensure a multi-dimensional array is split into its outermost length and inner array type.
+        let array = ArrayType::<CurrentNetwork>::from_str("[[u8; 2u32]; 3u32]")?;
+        assert_eq!(array.split_first(), (3, Some(ArrayType::from_str("[u8; 2u32]")?)));
+
+        // Ensure a one-dimensional array has no inner array type.
+        let array = ArrayType::<CurrentNetwork>::from_str("[u8; 4u32]")?;
+        assert_eq!(array.split_first(), (4, None));
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_numeric() -> Result<()> {
+        // Ensure arrays of integers and scalars are numeric, including multi-dimensional arrays.
+        assert!(ArrayType::<CurrentNetwork>::from_str("[u8; 4u32]")?.is_numeric());
+        assert!(ArrayType::<CurrentNetwork>::from_str("[[i64; 2u32]; 3u32]")?.is_numeric());
+        assert!(ArrayType::<CurrentNetwork>::from_str("[scalar; 2u32]")?.is_numeric());
+
+        // Ensure arrays of other literals and structs are not numeric.
+        assert!(!ArrayType::<CurrentNetwork>::from_str("[address; 2u32]")?.is_numeric());
+        assert!(!ArrayType::<CurrentNetwork>::from_str("[field; 2u32]")?.is_numeric());
+        assert!(!ArrayType::<CurrentNetwork>::from_str("[foo; 2u32]")?.is_numeric());
+        Ok(())
+    }
 }
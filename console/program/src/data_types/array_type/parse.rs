@@ -24,6 +24,11 @@ impl<N: Network> Parser for ArrayType<N> {
             alt((map(LiteralType::parse, PlaintextType::from), map(Identifier::parse, PlaintextType::from)))(string)
         }
 
+        // A helper function to recognize a length, i.e. `3u32`, without checking its type.
+        fn parse_length_token(string: &str) -> ParserResult<&str> {
+            recognize(many1(alt((alphanumeric1, tag("_")))))(string)
+        }
+
         // A helper function to parse the length of each dimension.
         fn parse_length<N: Network>(string: &str) -> ParserResult<U32<N>> {
             // Parse the whitespaces from the string.
@@ -32,6 +37,17 @@ impl<N: Network> Parser for ArrayType<N> {
             let (string, _) = tag(";")(string)?;
             // Parse the whitespaces from the string.
             let (string, _) = Sanitizer::parse_whitespaces(string)?;
+            // Ensure the length is not followed by another length, i.e. `[u8; 3u32 4u32]`.
+            context(
+                "unexpected additional dimension; use nested brackets",
+                map_res(
+                    pair(opt(parse_length_token), pair(Sanitizer::parse_whitespaces, opt(parse_length_token))),
+                    |(length, (_, additional))| match (length, additional) {
+                        (Some(_), Some(_)) => Err(error("unexpected additional dimension; use nested brackets")),
+                        _ => Ok(()),
+                    },
+                ),
+            )(string)?;
             // Parse the length.
             let (string, length) = U32::parse(string)?;
             // Parse the whitespaces from the string.
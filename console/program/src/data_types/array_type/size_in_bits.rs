@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{Identifier, StructType};
+
+impl<N: Network> ArrayType<N> {
+    /// Returns the number of bits in a value of the array type, without materializing the value.
+    /// This is the size in bits of the base element type, multiplied by the length of each dimension.
+    /// Struct definitions are resolved using the given `get_struct` function.
+    pub fn size_in_bits<'a>(&self, get_struct: &impl Fn(&Identifier<N>) -> Result<&'a StructType<N>>) -> Result<usize> {
+        self.size_with(get_struct, &|literal_type| literal_type.size_in_bits::<N>() as usize)
+    }
+
+    /// Returns the size of the array type, where each literal is sized by `literal_size`.
+    pub(crate) fn size_with<'a>(
+        &self,
+        get_struct: &impl Fn(&Identifier<N>) -> Result<&'a StructType<N>>,
+        literal_size: &impl Fn(&LiteralType) -> usize,
+    ) -> Result<usize> {
+        self.next_element_type()
+            .size_with(get_struct, literal_size)?
+            .checked_mul(**self.length() as usize)
+            .ok_or_else(|| anyhow!("Overflowed while computing the size of array '{self}'"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_types::test_helpers::struct_resolver;
+    use snarkvm_console_network::MainnetV0;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_size_in_bits() -> Result<()> {
+        // Initialize the structs.
+        let structs = [StructType::<CurrentNetwork>::from_str("struct foo:\n    a as u8;\n    b as [u16; 2u32];")?];
+        let get_struct = struct_resolver(&structs);
+
+        // Check a literal array.
+        let array = ArrayType::<CurrentNetwork>::from_str("[u8; 4u32]")?;
+        assert_eq!(array.size_in_bits(&get_struct)?, 32);
+
+        // Check a multi-dimensional array.
+        let array = ArrayType::<CurrentNetwork>::from_str("[[u16; 2u32]; 3u32]")?;
+        assert_eq!(array.size_in_bits(&get_struct)?, 96);
+
+        // Check a struct array.
+        let array = ArrayType::<CurrentNetwork>::from_str("[foo; 3u32]")?;
+        assert_eq!(array.size_in_bits(&get_struct)?, 3 * (8 + 2 * 16));
+
+        // Check an array of an undefined struct.
+        let array = ArrayType::<CurrentNetwork>::from_str("[bar; 2u32]")?;
+        assert!(array.size_in_bits(&get_struct).is_err());
+
+        Ok(())
+    }
+}
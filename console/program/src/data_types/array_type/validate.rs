@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{Identifier, StructType};
+
+impl<N: Network> ArrayType<N> {
+    /// Ensures the base element type of the array is defined, if it is a struct.
+    /// Struct definitions are resolved using the given `get_struct` function.
+    pub fn validate<'a>(&self, get_struct: &impl Fn(&Identifier<N>) -> Result<&'a StructType<N>>) -> Result<()> {
+        match self.base_element_type() {
+            PlaintextType::Struct(struct_name) => match get_struct(struct_name) {
+                Ok(_) => Ok(()),
+                Err(error) => bail!("Struct '{struct_name}' in array type '{self}' does not exist: {error}"),
+            },
+            PlaintextType::Literal(..) | PlaintextType::Array(..) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_types::test_helpers::struct_resolver;
+    use snarkvm_console_network::MainnetV0;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_validate() -> Result<()> {
+        // Initialize the structs.
+        let structs = [StructType::<CurrentNetwork>::from_str("struct foo:\n    a as u8;")?];
+        let get_struct = struct_resolver(&structs);
+
+        // Check arrays of literals and of an existing struct.
+        ArrayType::<CurrentNetwork>::from_str("[u8; 4u32]")?.validate(&get_struct)?;
+        ArrayType::<CurrentNetwork>::from_str("[foo; 2u32]")?.validate(&get_struct)?;
+        ArrayType::<CurrentNetwork>::from_str("[[foo; 2u32]; 3u32]")?.validate(&get_struct)?;
+
+        // Check arrays of a nonexistent struct.
+        let error = ArrayType::<CurrentNetwork>::from_str("[bar; 2u32]")?.validate(&get_struct).unwrap_err();
+        assert!(error.to_string().contains("Struct 'bar'"));
+        assert!(ArrayType::<CurrentNetwork>::from_str("[[bar; 2u32]; 3u32]")?.validate(&get_struct).is_err());
+        Ok(())
+    }
+}
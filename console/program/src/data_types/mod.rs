@@ -35,3 +35,22 @@ pub use struct_type::StructType;
 
 mod value_type;
 pub use value_type::ValueType;
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use crate::Identifier;
+    use snarkvm_console_network::prelude::*;
+
+    /// Returns a `get_struct` function that resolves the given struct definitions by name.
+    pub(crate) fn struct_resolver<'a, N: Network>(
+        structs: &'a [StructType<N>],
+    ) -> impl Fn(&Identifier<N>) -> Result<&'a StructType<N>> + 'a {
+        move |name| {
+            structs
+                .iter()
+                .find(|struct_type| struct_type.name() == name)
+                .ok_or_else(|| anyhow!("Struct '{name}' does not exist"))
+        }
+    }
+}
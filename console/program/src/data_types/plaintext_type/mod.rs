@@ -15,6 +15,7 @@
 mod bytes;
 mod parse;
 mod serialize;
+mod size;
 
 use crate::{ArrayType, Identifier, LiteralType};
 use snarkvm_console_network::prelude::*;
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::StructType;
+
+impl<N: Network> PlaintextType<N> {
+    /// Returns the number of bits in a value of the plaintext type, without materializing the value.
+    /// Struct definitions are resolved using the given `get_struct` function.
+    pub fn size_in_bits<'a>(&self, get_struct: &impl Fn(&Identifier<N>) -> Result<&'a StructType<N>>) -> Result<usize> {
+        self.size_with(get_struct, &|literal_type| literal_type.size_in_bits::<N>() as usize)
+    }
+
+    /// Returns the packed size in bytes of a value of the plaintext type, where each struct is sized
+    /// as the sum of its members. Struct definitions are resolved using the given `get_struct` function.
+    pub fn size_in_bytes<'a>(
+        &self,
+        get_struct: &impl Fn(&Identifier<N>) -> Result<&'a StructType<N>>,
+    ) -> Result<usize> {
+        self.size_with(get_struct, &|literal_type| literal_type.size_in_bytes::<N>() as usize)
+    }
+
+    /// Returns the size of the plaintext type, where each literal is sized by `literal_size`.
+    pub(crate) fn size_with<'a>(
+        &self,
+        get_struct: &impl Fn(&Identifier<N>) -> Result<&'a StructType<N>>,
+        literal_size: &impl Fn(&LiteralType) -> usize,
+    ) -> Result<usize> {
+        match self {
+            Self::Literal(literal_type) => Ok(literal_size(literal_type)),
+            Self::Struct(struct_name) => {
+                get_struct(struct_name)?.members().values().try_fold(0usize, |size, member_type| {
+                    size.checked_add(member_type.size_with(get_struct, literal_size)?)
+                        .ok_or_else(|| anyhow!("Overflowed while computing the size of struct '{struct_name}'"))
+                })
+            }
+            Self::Array(array_type) => array_type.size_with(get_struct, literal_size),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_types::test_helpers::struct_resolver;
+    use snarkvm_console_network::MainnetV0;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_size() -> Result<()> {
+        // Initialize the structs.
+        let structs = [StructType::<CurrentNetwork>::from_str("struct foo:\n    a as u8;\n    b as [u16; 2u32];")?];
+        let get_struct = struct_resolver(&structs);
+
+        // Check a literal.
+        let plaintext_type = PlaintextType::<CurrentNetwork>::from_str("u64")?;
+        assert_eq!(plaintext_type.size_in_bits(&get_struct)?, 64);
+        assert_eq!(plaintext_type.size_in_bytes(&get_struct)?, 8);
+
+        // Check a struct.
+        let plaintext_type = PlaintextType::<CurrentNetwork>::from_str("foo")?;
+        assert_eq!(plaintext_type.size_in_bits(&get_struct)?, 8 + 2 * 16);
+        assert_eq!(plaintext_type.size_in_bytes(&get_struct)?, 1 + 2 * 2);
+
+        // Check an array of structs.
+        let plaintext_type = PlaintextType::<CurrentNetwork>::from_str("[foo; 3u32]")?;
+        assert_eq!(plaintext_type.size_in_bits(&get_struct)?, 3 * (8 + 2 * 16));
+        assert_eq!(plaintext_type.size_in_bytes(&get_struct)?, 3 * (1 + 2 * 2));
+
+        // Check an undefined struct.
+        let plaintext_type = PlaintextType::<CurrentNetwork>::from_str("bar")?;
+        assert!(plaintext_type.size_in_bits(&get_struct).is_err());
+        assert!(plaintext_type.size_in_bytes(&get_struct).is_err());
+
+        Ok(())
+    }
+}
@@ -30,6 +30,15 @@ pub struct StructType<N: Network> {
 }
 
 impl<N: Network> StructType<N> {
+    /// Initializes a new struct type from the given name and members.
+    pub fn new(name: Identifier<N>, members: IndexMap<Identifier<N>, PlaintextType<N>>) -> Result<Self> {
+        // Ensure the number of members is within the allowed range.
+        ensure!(!members.is_empty(), "A struct must have at least one member");
+        ensure!(members.len() <= N::MAX_STRUCT_ENTRIES, "A struct can have at most {} members", N::MAX_STRUCT_ENTRIES);
+        // Construct the struct type.
+        Ok(Self { name, members })
+    }
+
     /// Returns the name of the struct type.
     #[inline]
     pub const fn name(&self) -> &Identifier<N> {
@@ -98,6 +98,18 @@ impl<N: Network> TryFrom<&str> for ProgramID<N> {
 }
 
 impl<N: Network> ProgramID<N> {
+    /// Parses a program ID from a string, ensuring its network-level domain is the network's program suffix.
+    pub fn parse_checked(program_id: &str) -> Result<Self> {
+        // Ensure the program ID ends in the expected suffix.
+        match program_id.rsplit_once('.') {
+            Some((_, suffix)) if suffix == N::PROGRAM_SUFFIX => Self::from_str(program_id),
+            Some((_, suffix)) => {
+                bail!("Program ID '{program_id}' has suffix '.{suffix}', expected '.{}'", N::PROGRAM_SUFFIX)
+            }
+            None => bail!("Program ID '{program_id}' is missing the '.{}' suffix", N::PROGRAM_SUFFIX),
+        }
+    }
+
     /// Returns the program name.
     #[inline]
     pub const fn name(&self) -> &Identifier<N> {
@@ -185,4 +197,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_checked() -> Result<()> {
+        assert_eq!(CurrentNetwork::PROGRAM_SUFFIX, "aleo");
+        // Ensure a program ID with the network's suffix is accepted.
+        assert_eq!(ProgramID::<CurrentNetwork>::parse_checked("foo.aleo")?, ProgramID::from_str("foo.aleo")?);
+        // Ensure a program ID with a foreign suffix is rejected.
+        assert!(ProgramID::<CurrentNetwork>::parse_checked("foo.eth").is_err());
+        // Ensure a program ID without a suffix is rejected.
+        assert!(ProgramID::<CurrentNetwork>::parse_checked("foo").is_err());
+        // Ensure an invalid program name is still rejected.
+        assert!(ProgramID::<CurrentNetwork>::parse_checked("Foo.aleo").is_err());
+        Ok(())
+    }
 }
@@ -162,7 +162,7 @@ impl<N: Network> Response<N> {
                         // Encrypt the record, using the randomizer.
                         let encrypted_record = record.encrypt(randomizer)?;
                         // Compute the record checksum, as the hash of the encrypted record.
-                        let checksum = N::hash_bhp1024(&encrypted_record.to_bits_le())?;
+                        let checksum = N::compute_ciphertext_checksum(&encrypted_record.to_bits_le())?;
 
                         // Return the output ID.
                         Ok(OutputID::Record(commitment, checksum))
@@ -78,6 +78,8 @@ mod tests {
         // We ensure 2^TRANSACTION_DEPTH - 1 == MAX_FUNCTIONS.
         // The "- 1" is for the fee transition.
         assert_eq!((2u32.checked_pow(TRANSACTION_DEPTH as u32).unwrap() - 1) as usize, CurrentNetwork::MAX_FUNCTIONS);
+        // We ensure 2^TRANSACTION_DEPTH == MAX_TRANSITIONS.
+        assert_eq!(2u32.checked_pow(TRANSACTION_DEPTH as u32).unwrap() as usize, CurrentNetwork::MAX_TRANSITIONS);
     }
 
     #[test]
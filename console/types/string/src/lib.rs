@@ -27,6 +27,7 @@ pub use snarkvm_console_types_field::Field;
 pub use snarkvm_console_types_integers::Integer;
 
 use core::marker::PhantomData;
+use zeroize::Zeroize;
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct StringType<E: Environment> {
@@ -38,6 +39,13 @@ pub struct StringType<E: Environment> {
 
 impl<E: Environment> StringTrait for StringType<E> {}
 
+impl<E: Environment> Zeroize for StringType<E> {
+    /// Overwrites the underlying string with zeros, and clears it.
+    fn zeroize(&mut self) {
+        self.string.zeroize();
+    }
+}
+
 impl<E: Environment> StringType<E> {
     /// Initializes a new string.
     pub fn new(string: &str) -> Self {
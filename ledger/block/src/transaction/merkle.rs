@@ -16,7 +16,14 @@ use super::*;
 
 impl<N: Network> Transaction<N> {
     /// The maximum number of transitions allowed in a transaction.
-    pub const MAX_TRANSITIONS: usize = usize::pow(2, TRANSACTION_DEPTH as u32);
+    /// This is checked at compile time to fit within the leaves of a transaction Merkle tree.
+    pub const MAX_TRANSITIONS: usize = {
+        assert!(
+            N::MAX_TRANSITIONS <= usize::pow(2, TRANSACTION_DEPTH as u32),
+            "The maximum number of transitions exceeds the transaction Merkle tree size"
+        );
+        N::MAX_TRANSITIONS
+    };
 
     /// Returns the transaction root, by computing the root for a Merkle tree of the transition IDs.
     pub fn to_root(&self) -> Result<Field<N>> {
@@ -198,7 +205,7 @@ impl<N: Network> Transaction<N> {
         );
         // Ensure the number of functions is within the allowed range.
         ensure!(
-            functions.len() < Self::MAX_TRANSITIONS, // Note: Observe we hold back 1 for the fee.
+            N::within_transition_limit(functions.len().saturating_add(1)), // Note: Observe we hold back 1 for the fee.
             "Deployment must contain less than {} functions, found {}",
             Self::MAX_TRANSITIONS,
             functions.len()
@@ -212,10 +219,31 @@ impl<N: Network> Transaction<N> {
         ensure!(num_transitions > 0, "Execution must contain at least one transition");
         // Ensure the number of functions is within the allowed range.
         ensure!(
-            num_transitions < Self::MAX_TRANSITIONS, // Note: Observe we hold back 1 for the fee.
-            "Execution must contain less than {num_transitions} transitions, found {}",
+            N::within_transition_limit(num_transitions.saturating_add(1)), // Note: Observe we hold back 1 for the fee.
+            "Execution must contain less than {} transitions, found {num_transitions}",
             Self::MAX_TRANSITIONS,
         );
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_check_execution_size() {
+        let max = Transaction::<CurrentNetwork>::MAX_TRANSITIONS;
+
+        // Ensure an execution must contain at least one transition.
+        assert!(Transaction::<CurrentNetwork>::check_execution_size(0).is_err());
+        // Ensure an execution may use every transition except the one held back for the fee.
+        assert!(Transaction::<CurrentNetwork>::check_execution_size(1).is_ok());
+        assert!(Transaction::<CurrentNetwork>::check_execution_size(max - 1).is_ok());
+        // Ensure an execution at or above the limit is rejected.
+        assert!(Transaction::<CurrentNetwork>::check_execution_size(max).is_err());
+        assert!(Transaction::<CurrentNetwork>::check_execution_size(max + 1).is_err());
+    }
+}
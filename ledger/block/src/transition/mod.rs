@@ -217,7 +217,7 @@ impl<N: Network> Transition<N> {
                         // Encrypt the record, using the randomizer.
                         let record_ciphertext = record.encrypt(randomizer)?;
                         // Compute the record checksum, as the hash of the encrypted record.
-                        let ciphertext_checksum = N::hash_bhp1024(&record_ciphertext.to_bits_le())?;
+                        let ciphertext_checksum = N::compute_ciphertext_checksum(&record_ciphertext.to_bits_le())?;
                         // Ensure the checksum matches.
                         ensure!(*checksum == ciphertext_checksum, "The output record ciphertext checksum is incorrect");
 
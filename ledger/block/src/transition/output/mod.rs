@@ -208,10 +208,7 @@ impl<N: Network> Output<N> {
                     Err(error) => Err(error),
                 }
             }
-            Output::Record(_, checksum, Some(value)) => match N::hash_bhp1024(&value.to_bits_le()) {
-                Ok(candidate_hash) => Ok(checksum == &candidate_hash),
-                Err(error) => Err(error),
-            },
+            Output::Record(_, checksum, Some(value)) => N::verify_ciphertext_checksum(checksum, &value.to_bits_le()),
             Output::Future(hash, Some(output)) => {
                 match output.to_fields() {
                     Ok(fields) => {
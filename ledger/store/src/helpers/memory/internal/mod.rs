@@ -17,3 +17,6 @@ pub use map::*;
 
 mod nested_map;
 pub use nested_map::*;
+
+mod sharded_nested_map;
+pub use sharded_nested_map::*;
@@ -0,0 +1,511 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::type_complexity)]
+
+use super::NestedMemoryMap;
+use crate::helpers::{NestedMap, NestedMapRead};
+use console::network::prelude::*;
+
+use core::hash::Hash;
+use parking_lot::Mutex;
+use std::{
+    borrow::Cow,
+    collections::{hash_map::Entry, HashMap},
+    sync::Arc,
+};
+
+/// A nested in-memory map that distributes its map-key-value entries across a fixed number of shards.
+///
+/// Each shard is a `NestedMemoryMap`, which holds its own atomic batch; the sharded map only routes each
+/// operation to its shard, using the given `route` function, and forwards batch operations to every shard.
+/// With a single shard, every operation is forwarded to the underlying `NestedMemoryMap` unchanged.
+///
+/// With multiple shards, `get_map_confirmed` and `get_map_speculative` order the entries of a map by their
+/// serialized keys, as in an unsharded map, while the confirmed iterators visit the shards one after another.
+///
+/// If the lengths are tracked, the length of every map written in an atomic batch is kept up to date
+/// as the batch progresses, so that `len_map_speculative` does not materialize the map.
+#[derive(Clone)]
+pub struct ShardedNestedMemoryMap<
+    M: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    K: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> {
+    shards: Arc<Vec<NestedMemoryMap<M, K, V>>>,
+    route: fn(&M, &K) -> Result<u64>,
+    track_lens: bool,
+    pending_lens: Arc<Mutex<PendingLens<M>>>,
+}
+
+/// The speculative lengths of the maps written in the current atomic batch, along with a snapshot of them
+/// for each atomic checkpoint.
+struct PendingLens<M> {
+    lens: HashMap<M, usize>,
+    checkpoints: Vec<HashMap<M, usize>>,
+}
+
+impl<
+        M: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        K: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    > ShardedNestedMemoryMap<M, K, V>
+{
+    /// Initializes a new sharded map with the given number of shards, where each map-key pair is stored
+    /// in the shard selected by its `route`, modulo the number of shards.
+    /// If `num_shards` is zero, a single shard is used.
+    pub fn new(num_shards: usize, route: fn(&M, &K) -> Result<u64>) -> Self {
+        Self {
+            shards: Arc::new((0..num_shards.max(1)).map(|_| NestedMemoryMap::default()).collect()),
+            route,
+            track_lens: false,
+            pending_lens: Arc::new(Mutex::new(PendingLens { lens: HashMap::new(), checkpoints: Vec::new() })),
+        }
+    }
+
+    /// Tracks the speculative length of every map written in an atomic batch.
+    /// Note: This adds a speculative lookup of the key to every insert and removal during an atomic batch.
+    pub fn track_lens(&mut self) {
+        self.track_lens = true;
+    }
+
+    /// Returns the number of keys in the given map, including the pending writes of the atomic batch.
+    /// If the lengths are not tracked, the speculative entries of the map are materialized.
+    pub fn len_map_speculative(&self, map: &M) -> Result<usize> {
+        // If no batch is in progress, return the confirmed length.
+        if !self.is_atomic_in_progress() {
+            return self.len_map_confirmed(map);
+        }
+        match self.track_lens {
+            true => match self.pending_lens.lock().lens.get(map) {
+                Some(len) => Ok(*len),
+                // The map has not been written in the batch.
+                None => self.len_map_confirmed(map),
+            },
+            false => Ok(self.get_map_speculative(map)?.len()),
+        }
+    }
+
+    /// Returns the number of shards.
+    pub fn num_shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Returns the shard responsible for the given map-key pair.
+    fn shard(&self, map: &M, key: &K) -> Result<&NestedMemoryMap<M, K, V>> {
+        // If there is only one shard, skip the routing.
+        if self.shards.len() == 1 {
+            return Ok(&self.shards[0]);
+        }
+        Ok(&self.shards[((self.route)(map, key)? % self.shards.len() as u64) as usize])
+    }
+
+    /// Clears the speculative lengths of the maps, along with their checkpoints.
+    fn clear_pending_lens(&self) {
+        let mut pending_lens = self.pending_lens.lock();
+        pending_lens.lens.clear();
+        pending_lens.checkpoints.clear();
+    }
+
+    /// Returns the speculative length of the given map, which is initialized to its confirmed length
+    /// the first time the map is written in the atomic batch.
+    fn pending_len<'b>(&self, lens: &'b mut HashMap<M, usize>, map: &M) -> Result<&'b mut usize> {
+        match lens.entry(*map) {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => Ok(entry.insert(self.len_map_confirmed(map)?)),
+        }
+    }
+
+    /// Returns the given key-value pairs, ordered by their serialized keys.
+    fn order_by_key(key_values: Vec<(K, V)>) -> Result<Vec<(K, V)>> {
+        let mut key_values = key_values
+            .into_iter()
+            .map(|(key, value)| Ok((bincode::serialize(&key)?, (key, value))))
+            .collect::<Result<Vec<_>>>()?;
+        key_values.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        Ok(key_values.into_iter().map(|(_, key_value)| key_value).collect())
+    }
+}
+
+impl<
+        'a,
+        M: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        K: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    > NestedMap<'a, M, K, V> for ShardedNestedMemoryMap<M, K, V>
+{
+    ///
+    /// Inserts the given map-key-value pair.
+    ///
+    fn insert(&self, map: M, key: K, value: V) -> Result<()> {
+        // If the lengths are not tracked or no batch is in progress, there is no speculative length to update.
+        if !self.track_lens || !self.is_atomic_in_progress() {
+            return self.shard(&map, &key)?.insert(map, key, value);
+        }
+        let mut pending_lens = self.pending_lens.lock();
+        let is_new = !self.contains_key_speculative(&map, &key)?;
+        let len = self.pending_len(&mut pending_lens.lens, &map)?;
+        self.shard(&map, &key)?.insert(map, key, value)?;
+        *len += is_new as usize;
+        Ok(())
+    }
+
+    ///
+    /// Removes the given map.
+    ///
+    fn remove_map(&self, map: &M) -> Result<()> {
+        self.shards.iter().try_for_each(|shard| shard.remove_map(map))?;
+        // If the lengths are tracked and a batch is in progress, the map is now speculatively empty.
+        if self.track_lens && self.is_atomic_in_progress() {
+            self.pending_lens.lock().lens.insert(*map, 0);
+        }
+        Ok(())
+    }
+
+    ///
+    /// Removes the key-value pair for the given map and key.
+    ///
+    fn remove_key(&self, map: &M, key: &K) -> Result<()> {
+        // If the lengths are not tracked or no batch is in progress, there is no speculative length to update.
+        if !self.track_lens || !self.is_atomic_in_progress() {
+            return self.shard(map, key)?.remove_key(map, key);
+        }
+        let mut pending_lens = self.pending_lens.lock();
+        let is_stored = self.contains_key_speculative(map, key)?;
+        let len = self.pending_len(&mut pending_lens.lens, map)?;
+        self.shard(map, key)?.remove_key(map, key)?;
+        *len = len.saturating_sub(is_stored as usize);
+        Ok(())
+    }
+
+    ///
+    /// Begins an atomic operation on every shard.
+    ///
+    fn start_atomic(&self) {
+        self.shards.iter().for_each(|shard| shard.start_atomic());
+    }
+
+    ///
+    /// Checks whether an atomic operation is currently in progress.
+    ///
+    fn is_atomic_in_progress(&self) -> bool {
+        // Note: The shards start and finish their atomic operations together.
+        self.shards[0].is_atomic_in_progress()
+    }
+
+    ///
+    /// Saves the current list of pending operations of every shard.
+    ///
+    fn atomic_checkpoint(&self) {
+        self.shards.iter().for_each(|shard| shard.atomic_checkpoint());
+        // If the lengths are tracked, snapshot the speculative lengths of the maps.
+        if self.track_lens {
+            let mut pending_lens = self.pending_lens.lock();
+            let lens = pending_lens.lens.clone();
+            pending_lens.checkpoints.push(lens);
+        }
+    }
+
+    ///
+    /// Removes the latest atomic checkpoint of every shard.
+    ///
+    fn clear_latest_checkpoint(&self) {
+        self.shards.iter().for_each(|shard| shard.clear_latest_checkpoint());
+        if self.track_lens {
+            let _ = self.pending_lens.lock().checkpoints.pop();
+        }
+    }
+
+    ///
+    /// Removes the pending operations of every shard to its last `atomic_checkpoint`.
+    ///
+    fn atomic_rewind(&self) {
+        self.shards.iter().for_each(|shard| shard.atomic_rewind());
+        // If the lengths are tracked, restore the speculative lengths of the maps as of the last checkpoint.
+        if self.track_lens {
+            let mut pending_lens = self.pending_lens.lock();
+            pending_lens.lens = pending_lens.checkpoints.pop().unwrap_or_default();
+        }
+    }
+
+    ///
+    /// Aborts the current atomic operation on every shard.
+    ///
+    fn abort_atomic(&self) {
+        self.shards.iter().for_each(|shard| shard.abort_atomic());
+        self.clear_pending_lens();
+    }
+
+    ///
+    /// Finishes the current atomic operation on every shard, performing all the queued writes.
+    ///
+    /// If a shard fails to finish, the atomic operations of it and of the remaining shards are aborted,
+    /// so that no shard is left with a batch in progress. The shards that finished before it keep their writes,
+    /// so the writes are only committed atomically across shards if every shard finishes.
+    /// Note: `NestedMemoryMap::finish_atomic` does not fail, so in practice every shard commits its writes.
+    ///
+    fn finish_atomic(&self) -> Result<()> {
+        self.clear_pending_lens();
+        for (index, shard) in self.shards.iter().enumerate() {
+            if let Err(error) = shard.finish_atomic() {
+                // Abort the failed shard and the remaining shards.
+                self.shards[index..].iter().for_each(|shard| shard.abort_atomic());
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<
+        'a,
+        M: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        K: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    > NestedMapRead<'a, M, K, V> for ShardedNestedMemoryMap<M, K, V>
+{
+    type Iterator =
+        core::iter::Flatten<std::vec::IntoIter<<NestedMemoryMap<M, K, V> as NestedMapRead<'a, M, K, V>>::Iterator>>;
+    type Keys = core::iter::Flatten<std::vec::IntoIter<<NestedMemoryMap<M, K, V> as NestedMapRead<'a, M, K, V>>::Keys>>;
+    type PendingIterator = core::iter::Flatten<
+        std::vec::IntoIter<<NestedMemoryMap<M, K, V> as NestedMapRead<'a, M, K, V>>::PendingIterator>,
+    >;
+    type Values =
+        core::iter::Flatten<std::vec::IntoIter<<NestedMemoryMap<M, K, V> as NestedMapRead<'a, M, K, V>>::Values>>;
+
+    ///
+    /// Returns the number of confirmed entries in the map.
+    ///
+    fn len_map_confirmed(&self, map: &M) -> Result<usize> {
+        self.shards.iter().map(|shard| shard.len_map_confirmed(map)).sum()
+    }
+
+    ///
+    /// Returns `true` if the given key exists in the map.
+    ///
+    fn contains_key_confirmed(&self, map: &M, key: &K) -> Result<bool> {
+        self.shard(map, key)?.contains_key_confirmed(map, key)
+    }
+
+    ///
+    /// Returns `true` if the given key exists in the map.
+    /// This method first checks the atomic batch, and if it does not exist, then checks the map.
+    ///
+    fn contains_key_speculative(&self, map: &M, key: &K) -> Result<bool> {
+        self.shard(map, key)?.contains_key_speculative(map, key)
+    }
+
+    ///
+    /// Returns the confirmed key-value pairs for the given map, if it exists.
+    ///
+    fn get_map_confirmed(&'a self, map: &M) -> Result<Vec<(K, V)>> {
+        // If there is only one shard, its key-value pairs are already ordered.
+        if self.shards.len() == 1 {
+            return self.shards[0].get_map_confirmed(map);
+        }
+        let mut key_values = Vec::new();
+        for shard in self.shards.iter() {
+            key_values.extend(shard.get_map_confirmed(map)?);
+        }
+        Self::order_by_key(key_values)
+    }
+
+    ///
+    /// Returns the speculative key-value pairs for the given map, if it exists.
+    ///
+    fn get_map_speculative(&'a self, map: &M) -> Result<Vec<(K, V)>> {
+        // If there is only one shard, return its key-value pairs as they are.
+        if self.shards.len() == 1 {
+            return self.shards[0].get_map_speculative(map);
+        }
+        let mut key_values = Vec::new();
+        for shard in self.shards.iter() {
+            key_values.extend(shard.get_map_speculative(map)?);
+        }
+        Self::order_by_key(key_values)
+    }
+
+    ///
+    /// Returns the value for the given key from the map, if it exists.
+    ///
+    fn get_value_confirmed(&'a self, map: &M, key: &K) -> Result<Option<Cow<'a, V>>> {
+        self.shard(map, key)?.get_value_confirmed(map, key)
+    }
+
+    ///
+    /// Returns the current value for the given key if it is scheduled
+    /// to be inserted as part of an atomic batch.
+    ///
+    /// If the key does not exist, returns `None`.
+    /// If the key is removed in the batch, returns `Some(None)`.
+    /// If the key is inserted in the batch, returns `Some(Some(value))`.
+    ///
+    fn get_value_pending(&self, map: &M, key: &K) -> Option<Option<V>> {
+        // Note: The routing only fails for maps and keys that cannot be serialized, which cannot be pending.
+        self.shard(map, key).ok()?.get_value_pending(map, key)
+    }
+
+    ///
+    /// Returns an iterator visiting each map-key-value pair in the atomic batch of each shard.
+    ///
+    fn iter_pending(&'a self) -> Self::PendingIterator {
+        self.shards.iter().map(|shard| shard.iter_pending()).collect_vec().into_iter().flatten()
+    }
+
+    ///
+    /// Returns an iterator visiting each confirmed map-key-value pair, one shard after another.
+    ///
+    fn iter_confirmed(&'a self) -> Self::Iterator {
+        self.shards.iter().map(|shard| shard.iter_confirmed()).collect_vec().into_iter().flatten()
+    }
+
+    ///
+    /// Returns an iterator over each confirmed key, one shard after another.
+    ///
+    fn keys_confirmed(&'a self) -> Self::Keys {
+        self.shards.iter().map(|shard| shard.keys_confirmed()).collect_vec().into_iter().flatten()
+    }
+
+    ///
+    /// Returns an iterator over each confirmed value, one shard after another.
+    ///
+    fn values_confirmed(&'a self) -> Self::Values {
+        self.shards.iter().map(|shard| shard.values_confirmed()).collect_vec().into_iter().flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NUM_SHARDS: usize = 4;
+
+    /// Routes each map-key pair by its key.
+    fn route(_: &usize, key: &usize) -> Result<u64> {
+        Ok(*key as u64)
+    }
+
+    #[test]
+    fn test_insert_and_get_value_speculative() {
+        // Initialize a map.
+        let map: ShardedNestedMemoryMap<usize, usize, String> = ShardedNestedMemoryMap::new(NUM_SHARDS, route);
+
+        crate::helpers::test_helpers::nested_map::check_insert_and_get_value_speculative(map);
+    }
+
+    #[test]
+    fn test_remove_and_get_value_speculative() {
+        // Initialize a map.
+        let map: ShardedNestedMemoryMap<usize, usize, String> = ShardedNestedMemoryMap::new(NUM_SHARDS, route);
+
+        crate::helpers::test_helpers::nested_map::check_remove_and_get_value_speculative(map);
+    }
+
+    #[test]
+    fn test_contains_key() {
+        // Initialize a map.
+        let map: ShardedNestedMemoryMap<usize, usize, String> = ShardedNestedMemoryMap::new(NUM_SHARDS, route);
+
+        crate::helpers::test_helpers::nested_map::check_contains_key(map);
+    }
+
+    #[test]
+    fn test_get_map() {
+        // Initialize a map.
+        let map: ShardedNestedMemoryMap<usize, usize, String> = ShardedNestedMemoryMap::new(NUM_SHARDS, route);
+
+        crate::helpers::test_helpers::nested_map::check_get_map(map);
+    }
+
+    #[test]
+    fn test_check_iterators_match() {
+        // Initialize a map.
+        let map: ShardedNestedMemoryMap<usize, usize, String> = ShardedNestedMemoryMap::new(NUM_SHARDS, route);
+
+        crate::helpers::test_helpers::nested_map::check_iterators_match(map);
+    }
+
+    #[test]
+    fn test_atomic_writes_are_batched() {
+        // Initialize a map.
+        let map: ShardedNestedMemoryMap<usize, usize, String> = ShardedNestedMemoryMap::new(NUM_SHARDS, route);
+
+        crate::helpers::test_helpers::nested_map::check_atomic_writes_are_batched(map);
+    }
+
+    #[test]
+    fn test_atomic_writes_can_be_aborted() {
+        // Initialize a map.
+        let map: ShardedNestedMemoryMap<usize, usize, String> = ShardedNestedMemoryMap::new(NUM_SHARDS, route);
+
+        crate::helpers::test_helpers::nested_map::check_atomic_writes_can_be_aborted(map);
+    }
+
+    #[test]
+    fn test_entries_are_distributed_across_shards() {
+        // Initialize a map.
+        let map: ShardedNestedMemoryMap<usize, usize, String> = ShardedNestedMemoryMap::new(NUM_SHARDS, route);
+        assert_eq!(map.num_shards(), NUM_SHARDS);
+
+        // Insert entries into a single map.
+        for i in 0..100 {
+            map.insert(0, i, i.to_string()).unwrap();
+        }
+        assert_eq!(map.len_map_confirmed(&0).unwrap(), 100);
+
+        // Ensure more than one shard holds entries.
+        let num_used_shards = map.shards.iter().filter(|shard| shard.len_map_confirmed(&0).unwrap() > 0).count();
+        assert!(num_used_shards > 1);
+
+        // Ensure the entries of the map are returned in the same order as an unsharded map.
+        let unsharded: NestedMemoryMap<usize, usize, String> = (0..100).map(|i| (0, i, i.to_string())).collect();
+        assert_eq!(map.get_map_confirmed(&0).unwrap(), unsharded.get_map_confirmed(&0).unwrap());
+        // Ensure the confirmed iterators visit the same entries as an unsharded map.
+        let entries = map.iter_confirmed().sorted_by_key(|(_, k, _)| **k).collect_vec();
+        assert!(entries.into_iter().eq(unsharded.iter_confirmed()));
+    }
+
+    #[test]
+    fn test_len_map_speculative() {
+        // Initialize a map that tracks the lengths of its maps.
+        let mut map: ShardedNestedMemoryMap<usize, usize, String> = ShardedNestedMemoryMap::new(NUM_SHARDS, route);
+        map.track_lens();
+        for i in 0..10 {
+            map.insert(0, i, i.to_string()).unwrap();
+        }
+        assert_eq!(map.len_map_speculative(&0).unwrap(), 10);
+
+        // Ensure the length counts new keys, but not overwritten or missing ones.
+        map.start_atomic();
+        map.insert(0, 10, "10".to_string()).unwrap();
+        map.insert(0, 0, "0".to_string()).unwrap();
+        map.remove_key(&0, &1).unwrap();
+        map.remove_key(&0, &100).unwrap();
+        assert_eq!(map.len_map_speculative(&0).unwrap(), 10);
+        assert_eq!(map.len_map_speculative(&0).unwrap(), map.get_map_speculative(&0).unwrap().len());
+
+        // Ensure a rewind restores the length as of the checkpoint.
+        map.atomic_checkpoint();
+        map.insert(0, 11, "11".to_string()).unwrap();
+        map.remove_map(&0).unwrap();
+        assert_eq!(map.len_map_speculative(&0).unwrap(), 0);
+        map.atomic_rewind();
+        assert_eq!(map.len_map_speculative(&0).unwrap(), 10);
+
+        // Ensure the confirmed length matches once the batch is finished.
+        map.finish_atomic().unwrap();
+        assert_eq!(map.len_map_speculative(&0).unwrap(), 10);
+        assert_eq!(map.len_map_confirmed(&0).unwrap(), 10);
+    }
+}
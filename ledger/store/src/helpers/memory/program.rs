@@ -15,19 +15,29 @@
 #![allow(clippy::type_complexity)]
 
 use crate::{
-    helpers::memory::{MemoryMap, NestedMemoryMap},
+    helpers::{
+        memory::{MemoryMap, ShardedNestedMemoryMap},
+        MapRead,
+        NestedMapRead,
+    },
+    to_key_id,
     CommitteeStorage,
     CommitteeStore,
     FinalizeStorage,
+    MappingProof,
 };
 use console::{
     prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use ledger_committee::Committee;
+use synthesizer_program::FinalizeOperation;
 
 use aleo_std_storage::StorageMode;
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
+use parking_lot::Mutex;
+use std::sync::Arc;
 
 /// An in-memory finalize storage.
 #[derive(Clone)]
@@ -37,30 +47,384 @@ pub struct FinalizeMemory<N: Network> {
     /// The program ID map.
     program_id_map: MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
-    key_value_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    key_value_map: ShardedNestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
     /// The storage mode.
     storage_mode: StorageMode,
+    /// The retained snapshots of the confirmed state, by checkpoint ID.
+    checkpoints: Arc<Mutex<FinalizeCheckpoints<N>>>,
 }
 
-#[rustfmt::skip]
-impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
-    type CommitteeStorage = CommitteeMemory<N>;
-    type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
-    type KeyValueMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+/// A key recorded by a finalize storage, or a whole mapping if the key is `None`.
+type RecordedKey<N> = (ProgramID<N>, Identifier<N>, Option<Plaintext<N>>);
 
-    /// Initializes the finalize storage.
-    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+/// The default maximum number of retained checkpoint snapshots.
+const DEFAULT_MAX_CHECKPOINTS: usize = 16;
+
+/// A snapshot of the confirmed key-value entries, by program ID and mapping name.
+type FinalizeSnapshot<N> = IndexMap<(ProgramID<N>, Identifier<N>), IndexMap<Plaintext<N>, Value<N>>>;
+
+/// The retained snapshots of a finalize storage, from oldest to newest.
+struct FinalizeCheckpoints<N: Network> {
+    /// The ID of the next checkpoint.
+    next_id: u64,
+    /// The maximum number of retained snapshots.
+    max_checkpoints: usize,
+    /// The snapshots, by checkpoint ID.
+    snapshots: IndexMap<u64, FinalizeSnapshot<N>>,
+}
+
+impl<N: Network> FinalizeCheckpoints<N> {
+    /// Drops the oldest snapshots in excess of the bound.
+    fn prune(&mut self) {
+        let num_pruned = self.snapshots.len().saturating_sub(self.max_checkpoints);
+        self.snapshots.drain(..num_pruned);
+    }
+}
+
+/// The policy for resolving a key that holds differing values in two merged finalize storages.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MergePolicy {
+    /// Keep the value in this storage.
+    PreferSelf,
+    /// Overwrite the value with the one in the other storage.
+    PreferOther,
+    /// Abort the merge, leaving this storage unchanged.
+    ErrorOnConflict,
+}
+
+/// Returns the high 64 bits of the key ID of the given key, which select its shard in a sharded finalize storage.
+fn key_id_high_bits<N: Network>(mapping: &(ProgramID<N>, Identifier<N>), key: &Plaintext<N>) -> Result<u64> {
+    let key_id = to_key_id(&mapping.0, &mapping.1, key)?;
+    Ok(key_id.to_bits_be().into_iter().take(64).fold(0u64, |bits, bit| (bits << 1) | bit as u64))
+}
+
+impl<N: Network> FinalizeMemory<N> {
+    /// Initializes the finalize storage, distributing the key-value entries across `num_shards` shards
+    /// by the high bits of their key IDs. With a single shard, the key-value map is an unsharded map.
+    pub fn open_sharded<S: Clone + Into<StorageMode>>(storage: S, num_shards: usize) -> Result<Self> {
+        // Ensure the number of shards is nonzero.
+        ensure!(num_shards > 0, "The number of finalize shards must be greater than zero");
         // Initialize the committee store.
         let committee_store = CommitteeStore::<N, CommitteeMemory<N>>::open(storage.clone())?;
         // Return the finalize store.
         Ok(Self {
             committee_store,
             program_id_map: MemoryMap::default(),
-            key_value_map: NestedMemoryMap::default(),
+            key_value_map: ShardedNestedMemoryMap::new(num_shards, key_id_high_bits::<N>),
             storage_mode: storage.into(),
+            checkpoints: Arc::new(Mutex::new(FinalizeCheckpoints {
+                next_id: 0,
+                max_checkpoints: DEFAULT_MAX_CHECKPOINTS,
+                snapshots: IndexMap::new(),
+            })),
         })
     }
 
+    /// Returns the total number of confirmed key-value entries, across all mappings.
+    pub fn total_entries(&self) -> Result<usize> {
+        let mut total_entries = 0;
+        for (program_id, mapping_names) in self.program_id_map.iter_confirmed() {
+            for mapping_name in mapping_names.iter() {
+                total_entries += self.key_value_map.len_map_confirmed(&(*program_id, *mapping_name))?;
+            }
+        }
+        Ok(total_entries)
+    }
+
+    /// Returns the mapping root of the confirmed entries of the given mapping, against which mapping proofs verify.
+    ///
+    /// The mapping tree is not cached, and is rebuilt from every confirmed entry on each call.
+    /// As such, this is intended for development and tooling, and not for serving proofs on a hot path.
+    pub fn mapping_root(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<Field<N>> {
+        MappingProof::mapping_root(program_id, mapping_name, &self.get_mapping_confirmed(program_id, mapping_name)?)
+    }
+
+    /// Returns the confirmed value for the given key, along with a proof against the mapping root
+    /// that the key maps to the value, or that the key is absent from the mapping.
+    ///
+    /// The mapping tree is not cached, and is rebuilt from every confirmed entry on each call.
+    /// As such, this is intended for development and tooling, and not for serving proofs on a hot path.
+    pub fn get_with_proof(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<(Option<Value<N>>, MappingProof<N>)> {
+        // Retrieve the confirmed entries of the mapping.
+        let entries = self.get_mapping_confirmed(program_id, mapping_name)?;
+        // Prove the key against the entries.
+        let (_, proof) = MappingProof::prove(program_id, mapping_name, &entries, key)?;
+        // Retrieve the value of the key.
+        let value = entries.into_iter().find_map(|(entry_key, value)| (&entry_key == key).then_some(value));
+        Ok((value, proof))
+    }
+
+    /// Merges the confirmed entries of the other storage into this storage, initializing any missing mappings.
+    /// If a key holds differing values in both storages, the conflict is resolved with the given policy.
+    /// The entries are written in a single atomic batch, so a failed merge leaves this storage unchanged.
+    pub fn merge_from(&mut self, other: &FinalizeMemory<N>, policy: MergePolicy) -> Result<()> {
+        // Collect the entries to write, so that a conflict aborts the merge before any writes.
+        let mut mappings = Vec::new();
+        for (program_id, mapping_names) in other.program_id_map.iter_confirmed() {
+            for mapping_name in mapping_names.iter() {
+                let mut entries = Vec::new();
+                for (key, value) in other.get_mapping_confirmed(*program_id, *mapping_name)? {
+                    match self.key_value_map.get_value_confirmed(&(*program_id, *mapping_name), &key)? {
+                        // The key holds the same value in both storages.
+                        Some(existing) if *existing == value => (),
+                        // The key holds differing values, so apply the policy.
+                        Some(_) => match policy {
+                            MergePolicy::PreferSelf => (),
+                            MergePolicy::PreferOther => entries.push((key, value)),
+                            MergePolicy::ErrorOnConflict => {
+                                bail!("Found conflicting values for key '{key}' in '{program_id}/{mapping_name}'")
+                            }
+                        },
+                        // The key only exists in the other storage.
+                        None => entries.push((key, value)),
+                    }
+                }
+                mappings.push((*program_id, *mapping_name, entries));
+            }
+        }
+
+        // Write the entries into this storage, in a single atomic batch.
+        self.transaction(|finalize_memory| {
+            for (program_id, mapping_name, entries) in mappings {
+                // Initialize the mapping, if it does not exist.
+                if !finalize_memory.contains_mapping_speculative(&program_id, &mapping_name)? {
+                    finalize_memory.initialize_mapping(program_id, mapping_name)?;
+                }
+                for (key, value) in entries {
+                    finalize_memory.update_key_value(program_id, mapping_name, key, value)?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Runs the given closure as a single atomic batch, committing its writes if it succeeds,
+    /// and rolling all of them back if it returns an error.
+    /// Note: Within the closure, writes are pending, and are only visible to speculative reads.
+    pub fn transaction<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        // Ensure there is no atomic batch in progress, as an abort would discard its writes as well.
+        ensure!(!self.is_atomic_in_progress(), "Cannot start a finalize transaction during another atomic batch");
+        // Start the atomic batch.
+        self.start_atomic();
+        // Run the closure, and commit or roll back its writes.
+        match f(self) {
+            Ok(output) => {
+                self.finish_atomic()?;
+                Ok(output)
+            }
+            Err(error) => {
+                self.abort_atomic();
+                Err(error)
+            }
+        }
+    }
+
+    /// Returns the value for the given key, inserting the value produced by `default` if the key is not present.
+    /// The default is only evaluated when the key is missing, so repeated calls return the stored value.
+    pub fn get_or_insert_with(
+        &mut self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+        default: impl FnOnce() -> Value<N>,
+    ) -> Result<Value<N>> {
+        // Return the stored value, if it exists.
+        if let Some(value) = self.get_value_speculative(program_id, mapping_name, &key)? {
+            return Ok(value);
+        }
+        // Otherwise, insert the default value.
+        let value = default();
+        self.insert_key_value(program_id, mapping_name, key, value.clone())?;
+        Ok(value)
+    }
+
+    /// Snapshots the confirmed state, and returns the ID of the checkpoint, which increases monotonically.
+    /// Only the most recent checkpoints are retained (see `set_max_checkpoints`); older ones are dropped.
+    pub fn checkpoint_with_id(&mut self) -> Result<u64> {
+        // Snapshot the confirmed entries of every mapping.
+        let mut snapshot = FinalizeSnapshot::new();
+        for (program_id, mapping_names) in self.program_id_map.iter_confirmed() {
+            for mapping_name in mapping_names.iter() {
+                let entries = self.get_mapping_confirmed(*program_id, *mapping_name)?;
+                snapshot.insert((*program_id, *mapping_name), entries.into_iter().collect());
+            }
+        }
+        // Retain the snapshot under the next checkpoint ID.
+        let mut checkpoints = self.checkpoints.lock();
+        let checkpoint_id = checkpoints.next_id;
+        checkpoints.next_id += 1;
+        checkpoints.snapshots.insert(checkpoint_id, snapshot);
+        checkpoints.prune();
+        Ok(checkpoint_id)
+    }
+
+    /// Sets the maximum number of retained checkpoints, dropping the oldest checkpoints in excess of it.
+    pub fn set_max_checkpoints(&mut self, max_checkpoints: usize) -> Result<()> {
+        // Ensure the bound is nonzero.
+        ensure!(max_checkpoints > 0, "The maximum number of finalize checkpoints must be greater than zero");
+        let mut checkpoints = self.checkpoints.lock();
+        checkpoints.max_checkpoints = max_checkpoints;
+        checkpoints.prune();
+        Ok(())
+    }
+
+    /// Returns the value for the given key, as of the given checkpoint.
+    /// This errors if the checkpoint does not exist, or is no longer retained.
+    pub fn get_value_at(
+        &self,
+        checkpoint_id: u64,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        let checkpoints = self.checkpoints.lock();
+        // Retrieve the snapshot of the checkpoint.
+        let Some(snapshot) = checkpoints.snapshots.get(&checkpoint_id) else {
+            bail!("Finalize checkpoint '{checkpoint_id}' does not exist or is no longer retained")
+        };
+        // Retrieve the value from the snapshot.
+        match snapshot.get(&(program_id, mapping_name)) {
+            Some(entries) => Ok(entries.get(key).cloned()),
+            None => bail!("Mapping '{program_id}/{mapping_name}' is not initialized at checkpoint '{checkpoint_id}'"),
+        }
+    }
+
+    /// Returns the confirmed entries of the given mapping whose values satisfy the given predicate,
+    /// ordered by their key IDs. This order is independent of the insertion order and of the sharding of the storage.
+    pub fn filter_values(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        predicate: impl Fn(&Value<N>) -> bool,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        let mut entries = self.get_mapping_confirmed(program_id, mapping_name)?;
+        entries.retain(|(_, value)| predicate(value));
+        // Order the entries by their key IDs.
+        let mut entries = entries
+            .into_iter()
+            .map(|(key, value)| Ok((to_key_id(&program_id, &mapping_name, &key)?, (key, value))))
+            .collect::<Result<Vec<_>>>()?;
+        entries.sort_unstable_by_key(|(key_id, _)| *key_id);
+        Ok(entries.into_iter().map(|(_, entry)| entry).collect())
+    }
+
+    /// Returns a read-only view of the confirmed finalize state, for query paths that must not mutate it.
+    pub fn as_read_only(&self) -> FinalizeReadView<'_, N> {
+        FinalizeReadView { finalize_memory: self }
+    }
+
+    /// Shrinks the retained checkpoint snapshots, reclaiming the capacity left by lowering the maximum number
+    /// of checkpoints. Note: This does not rebuild the program ID map or the key-value maps.
+    pub fn shrink_bookkeeping(&mut self) -> Result<()> {
+        // Shrink the retained snapshots.
+        let mut checkpoints = self.checkpoints.lock();
+        for snapshot in checkpoints.snapshots.values_mut() {
+            snapshot.values_mut().for_each(IndexMap::shrink_to_fit);
+            snapshot.shrink_to_fit();
+        }
+        checkpoints.snapshots.shrink_to_fit();
+        Ok(())
+    }
+
+    /// Returns the confirmed entries of the given mapping as CSV, with a `key,value` header row.
+    /// Each key and value is rendered in its `Display` form, and quoted if necessary.
+    pub fn export_csv(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<String> {
+        // Initialize the CSV with the header row.
+        let mut csv = String::from("key,value\n");
+        // Append a row for each entry in the mapping.
+        for (key, value) in self.get_mapping_confirmed(program_id, mapping_name)? {
+            csv.push_str(&format!("{},{}\n", escape_csv_field(&key.to_string()), escape_csv_field(&value.to_string())));
+        }
+        Ok(csv)
+    }
+
+    /// Replaces the entries of the given mapping with the rows of the given CSV, as produced by `export_csv`.
+    /// If the mapping is not yet initialized, it is initialized first.
+    pub fn import_csv(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>, csv: &str) -> Result<()> {
+        // Parse the rows, skipping the header row.
+        let mut rows = parse_csv(csv)?.into_iter().peekable();
+        if rows.peek().is_some_and(|row| row == &["key", "value"]) {
+            rows.next();
+        }
+        // Parse the entries, before modifying the mapping.
+        let entries = rows
+            .enumerate()
+            .map(|(i, row)| match row.as_slice() {
+                [key, value] => Ok((Plaintext::from_str(key)?, Value::from_str(value)?)),
+                _ => bail!("Expected 2 fields in CSV row {i}, found {}", row.len()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Initialize the mapping, if it does not exist.
+        if !self.contains_mapping_confirmed(&program_id, &mapping_name)? {
+            self.initialize_mapping(program_id, mapping_name)?;
+        }
+        // Replace the entries of the mapping.
+        self.replace_mapping(program_id, mapping_name, entries)?;
+        Ok(())
+    }
+}
+
+/// Returns the given field as a CSV field, quoting it if it contains a comma, quote, or newline.
+fn escape_csv_field(field: &str) -> String {
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+/// Parses the given CSV into rows of fields, unescaping any quoted fields.
+fn parse_csv(csv: &str) -> Result<Vec<Vec<String>>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+
+    let mut chars = csv.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (in_quotes, c) {
+            // A doubled quote inside a quoted field is an escaped quote, otherwise it closes the field.
+            (true, '"') => match chars.peek() {
+                Some('"') => field.push(chars.next().unwrap()),
+                _ => in_quotes = false,
+            },
+            (true, c) => field.push(c),
+            (false, '"') => in_quotes = true,
+            (false, ',') => row.push(core::mem::take(&mut field)),
+            (false, '\n') => {
+                row.push(core::mem::take(&mut field));
+                rows.push(core::mem::take(&mut row));
+            }
+            (false, '\r') => (),
+            (false, c) => field.push(c),
+        }
+    }
+    // Ensure every quoted field is closed.
+    ensure!(!in_quotes, "Found an unterminated quoted field in the CSV");
+    // Append the last row, if the CSV does not end with a newline.
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[rustfmt::skip]
+impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
+    type CommitteeStorage = CommitteeMemory<N>;
+    type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
+    type KeyValueMap = ShardedNestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+
+    /// Initializes the finalize storage.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Self::open_sharded(storage, 1)
+    }
+
     /// Initializes the test-variant of the storage.
     #[cfg(any(test, feature = "test"))]
     fn open_testing(_: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
@@ -88,6 +452,357 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     }
 }
 
+/// A read-only view of the confirmed state of a finalize storage.
+/// The view only exposes queries, so it cannot be used to mutate the backing storage.
+#[derive(Copy, Clone)]
+pub struct FinalizeReadView<'a, N: Network> {
+    /// The backing finalize storage.
+    finalize_memory: &'a FinalizeMemory<N>,
+}
+
+impl<N: Network> FinalizeReadView<'_, N> {
+    /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`.
+    pub fn get_value(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        self.finalize_memory.get_value_confirmed(program_id, mapping_name, key)
+    }
+
+    /// Returns `true` if the given `program ID`, `mapping name`, and `key` exist.
+    pub fn contains_key(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<bool> {
+        self.finalize_memory.contains_key_confirmed(program_id, mapping_name, key)
+    }
+
+    /// Returns the confirmed keys of the given mapping.
+    pub fn keys(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<Vec<Plaintext<N>>> {
+        Ok(self.entries(program_id, mapping_name)?.into_iter().map(|(key, _)| key).collect())
+    }
+
+    /// Returns the confirmed key-value entries of the given mapping.
+    pub fn entries(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        self.finalize_memory.get_mapping_confirmed(program_id, mapping_name)
+    }
+}
+
+/// An in-memory finalize storage that holds at most a fixed number of key-value entries,
+/// evicting the least-recently-used entries once the bound is exceeded.
+/// It also caps the number of key-value entries in a single mapping, rejecting new keys once a mapping is full.
+///
+/// Evicted entries are permanently removed, which changes the finalize state and its checksum,
+/// and the RocksDB storage does not enforce the mapping cap, so a finalize that is rejected here may succeed there.
+/// As such, this storage is only safe for development and ephemeral nodes, and must never be used for consensus.
+#[derive(Clone)]
+pub struct BoundedFinalizeMemory<N: Network> {
+    /// The backing finalize storage.
+    finalize_memory: FinalizeMemory<N>,
+    /// The maximum number of key-value entries.
+    max_entries: usize,
+    /// The maximum number of key-value entries in a single mapping.
+    max_mapping_entries: u64,
+    /// The tracked entries, keyed by key ID, from least to most recently used.
+    access_order: Arc<Mutex<IndexMap<Field<N>, (ProgramID<N>, Identifier<N>, Plaintext<N>)>>>,
+    /// The keys recorded during the current atomic batch, which are tracked once the batch is finished.
+    pending_keys: Arc<Mutex<Vec<RecordedKey<N>>>>,
+}
+
+impl<N: Network> BoundedFinalizeMemory<N> {
+    /// Initializes a finalize storage that holds at most `max_entries` key-value entries.
+    pub fn open_bounded<S: Clone + Into<StorageMode>>(storage: S, max_entries: usize) -> Result<Self> {
+        // Ensure the bound is nonzero.
+        ensure!(max_entries > 0, "The maximum number of finalize entries must be greater than zero");
+        // Initialize the finalize storage, tracking the length of each mapping during atomic batches.
+        let mut finalize_memory = FinalizeMemory::open(storage)?;
+        finalize_memory.key_value_map.track_lens();
+        Ok(Self {
+            finalize_memory,
+            max_entries,
+            max_mapping_entries: N::MAX_MAPPING_ENTRIES,
+            access_order: Default::default(),
+            pending_keys: Default::default(),
+        })
+    }
+
+    /// Sets the maximum number of key-value entries in a single mapping, which may not exceed `N::MAX_MAPPING_ENTRIES`.
+    /// Existing entries are retained, but no new keys may be inserted into a mapping that is at or above the bound.
+    /// Note: The bound is only enforced by this storage, so it is only intended for development and testing.
+    pub fn set_max_mapping_entries(&mut self, max_mapping_entries: u64) -> Result<()> {
+        // Ensure the bound is nonzero, and within the network bound.
+        ensure!(max_mapping_entries > 0, "The maximum number of mapping entries must be greater than zero");
+        ensure!(
+            max_mapping_entries <= N::MAX_MAPPING_ENTRIES,
+            "The maximum number of mapping entries cannot exceed {}",
+            N::MAX_MAPPING_ENTRIES
+        );
+        self.max_mapping_entries = max_mapping_entries;
+        Ok(())
+    }
+
+    /// Returns the total number of confirmed key-value entries, across all mappings.
+    pub fn total_entries(&self) -> Result<usize> {
+        self.finalize_memory.total_entries()
+    }
+
+    /// Ensures the given mapping has capacity to store the given key, counting the pending entries of an atomic batch.
+    fn ensure_mapping_capacity(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<()> {
+        // If the key already exists, it does not take up any additional capacity.
+        if self.contains_key_speculative(program_id, mapping_name, key)? {
+            return Ok(());
+        }
+        // Ensure the mapping is within capacity.
+        let num_entries = self.key_value_map().len_map_speculative(&(program_id, mapping_name))? as u64;
+        ensure!(
+            N::within_mapping_capacity(num_entries) && num_entries < self.max_mapping_entries,
+            "Illegal operation: '{program_id}/{mapping_name}' has reached the maximum of {} entries - cannot insert key-value.",
+            self.max_mapping_entries
+        );
+        Ok(())
+    }
+
+    /// Shrinks the eviction tracking to the live entries, preserving their order, along with the bookkeeping
+    /// of the backing storage. This drops the tracking of removed keys, and reclaims the capacity left
+    /// by insert and remove churn.
+    pub fn shrink_bookkeeping(&mut self) -> Result<()> {
+        // Collect the tracked keys that are no longer in storage.
+        let tracked = self.access_order.lock().iter().map(|(id, entry)| (*id, entry.clone())).collect::<Vec<_>>();
+        let mut stale = IndexSet::new();
+        for (key_id, (program_id, mapping_name, key)) in tracked {
+            if !self.contains_mapping_speculative(&program_id, &mapping_name)?
+                || !self.contains_key_speculative(program_id, mapping_name, &key)?
+            {
+                stale.insert(key_id);
+            }
+        }
+        // Rebuild the access order from the live keys.
+        {
+            let mut access_order = self.access_order.lock();
+            access_order.retain(|key_id, _| !stale.contains(key_id));
+            access_order.shrink_to_fit();
+        }
+        // Shrink the bookkeeping of the backing storage.
+        self.finalize_memory.shrink_bookkeeping()
+    }
+
+    /// Tracks the given keys for eviction, or the keys of a whole mapping if the key is `None`, once any atomic batch
+    /// is finished. Stored keys are moved to the most-recently-used position, keys that are no longer stored are
+    /// untracked, and the least-recently-used entries in excess of the bound are evicted.
+    fn track_keys(&self, keys: Vec<RecordedKey<N>>) -> Result<()> {
+        // If an atomic batch is in progress, defer the keys until it is finished.
+        if self.is_atomic_in_progress() {
+            self.pending_keys.lock().extend(keys);
+            return Ok(());
+        }
+
+        // Determine which of the keys are stored, along with their key IDs.
+        let mut tracked = Vec::with_capacity(keys.len());
+        for (program_id, mapping_name, key) in keys {
+            let entry = match key {
+                Some(key) => {
+                    let key_id = to_key_id(&program_id, &mapping_name, &key)?;
+                    let is_stored = self.contains_key_confirmed(program_id, mapping_name, &key)?;
+                    Some((key_id, key, is_stored))
+                }
+                None => None,
+            };
+            tracked.push((program_id, mapping_name, entry));
+        }
+
+        // Update the access order, and collect the entries in excess of the bound.
+        // Every stored entry is tracked, so bounding the tracked entries bounds the stored entries.
+        // Note that a read only moves an already-tracked key, so reads never cause evictions.
+        let evicted = {
+            let mut access_order = self.access_order.lock();
+            for (program_id, mapping_name, entry) in tracked {
+                match entry {
+                    Some((key_id, key, is_stored)) => {
+                        access_order.shift_remove(&key_id);
+                        if is_stored {
+                            access_order.insert(key_id, (program_id, mapping_name, key));
+                        }
+                    }
+                    None => access_order.retain(|_, (p, m, _)| (*p, *m) != (program_id, mapping_name)),
+                }
+            }
+            let num_evicted = access_order.len().saturating_sub(self.max_entries);
+            access_order.drain(..num_evicted).map(|(_, entry)| entry).collect::<Vec<_>>()
+        };
+
+        // Remove the evicted entries that are still in storage.
+        for (program_id, mapping_name, key) in evicted {
+            if self.contains_mapping_confirmed(&program_id, &mapping_name)? {
+                self.finalize_memory.remove_key_value(program_id, mapping_name, &key)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network> FinalizeStorage<N> for BoundedFinalizeMemory<N> {
+    type CommitteeStorage = CommitteeMemory<N>;
+    type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
+    type KeyValueMap = ShardedNestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+
+    /// Initializes the finalize storage, without a bound on the number of entries. See `open_bounded`.
+    fn open<S: Clone + Into<StorageMode>>(storage: S) -> Result<Self> {
+        Self::open_bounded(storage, usize::MAX)
+    }
+
+    /// Initializes the test-variant of the storage.
+    #[cfg(any(test, feature = "test"))]
+    fn open_testing(_: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        Self::open(dev)
+    }
+
+    /// Returns the committee store.
+    fn committee_store(&self) -> &CommitteeStore<N, Self::CommitteeStorage> {
+        self.finalize_memory.committee_store()
+    }
+
+    /// Returns the program ID map.
+    fn program_id_map(&self) -> &Self::ProgramIDMap {
+        self.finalize_memory.program_id_map()
+    }
+
+    /// Returns the key-value map.
+    fn key_value_map(&self) -> &Self::KeyValueMap {
+        self.finalize_memory.key_value_map()
+    }
+
+    /// Returns the storage mode.
+    fn storage_mode(&self) -> &StorageMode {
+        self.finalize_memory.storage_mode()
+    }
+
+    /// Aborts an atomic batch write operation, discarding the keys accessed during the batch.
+    fn abort_atomic(&self) {
+        self.finalize_memory.abort_atomic();
+        self.pending_keys.lock().clear();
+    }
+
+    /// Finishes an atomic batch write operation, and then tracks the keys accessed during the batch.
+    fn finish_atomic(&self) -> Result<()> {
+        self.finalize_memory.finish_atomic()?;
+        // Track the keys recorded during the batch, now that its writes are committed.
+        let pending_keys = core::mem::take(&mut *self.pending_keys.lock());
+        self.track_keys(pending_keys)
+    }
+
+    /// Stores the given `(key, value)` pair if the mapping has capacity for it, and tracks the key.
+    fn insert_key_value(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+        value: Value<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        self.ensure_mapping_capacity(program_id, mapping_name, &key)?;
+        let operation = self.finalize_memory.insert_key_value(program_id, mapping_name, key.clone(), value)?;
+        self.track_keys(vec![(program_id, mapping_name, Some(key))])?;
+        Ok(operation)
+    }
+
+    /// Stores the given `(key, value)` pair if the mapping has capacity for it, overwriting any existing value,
+    /// and tracks the key.
+    fn update_key_value(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+        value: Value<N>,
+    ) -> Result<FinalizeOperation<N>> {
+        self.ensure_mapping_capacity(program_id, mapping_name, &key)?;
+        let operation = self.finalize_memory.update_key_value(program_id, mapping_name, key.clone(), value)?;
+        self.track_keys(vec![(program_id, mapping_name, Some(key))])?;
+        Ok(operation)
+    }
+
+    /// Removes the key-value pair for the given key, and untracks the key.
+    fn remove_key_value(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<FinalizeOperation<N>>> {
+        let operation = self.finalize_memory.remove_key_value(program_id, mapping_name, key)?;
+        if operation.is_some() {
+            self.track_keys(vec![(program_id, mapping_name, Some(key.clone()))])?;
+        }
+        Ok(operation)
+    }
+
+    /// Replaces the entries of the given mapping, untracking the existing keys and tracking the new ones.
+    fn replace_mapping(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        entries: Vec<(Plaintext<N>, Value<N>)>,
+    ) -> Result<FinalizeOperation<N>> {
+        let keys = entries.iter().map(|(key, _)| (program_id, mapping_name, Some(key.clone()))).collect::<Vec<_>>();
+        let operation = self.finalize_memory.replace_mapping(program_id, mapping_name, entries)?;
+        self.track_keys(std::iter::once((program_id, mapping_name, None)).chain(keys).collect())?;
+        Ok(operation)
+    }
+
+    /// Removes the given mapping, and untracks its keys.
+    fn remove_mapping(&self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Result<FinalizeOperation<N>> {
+        let operation = self.finalize_memory.remove_mapping(program_id, mapping_name)?;
+        self.track_keys(vec![(program_id, mapping_name, None)])?;
+        Ok(operation)
+    }
+
+    /// Removes the given program, and untracks the keys of its mappings.
+    fn remove_program(&self, program_id: &ProgramID<N>) -> Result<()> {
+        let mapping_names = self.get_mapping_names_speculative(program_id)?.unwrap_or_default();
+        self.finalize_memory.remove_program(program_id)?;
+        self.track_keys(mapping_names.into_iter().map(|mapping_name| (*program_id, mapping_name, None)).collect())
+    }
+
+    /// Returns the confirmed value for the given key, and tracks the access to the key.
+    fn get_value_confirmed(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        let value = self.finalize_memory.get_value_confirmed(program_id, mapping_name, key)?;
+        if value.is_some() {
+            self.track_keys(vec![(program_id, mapping_name, Some(key.clone()))])?;
+        }
+        Ok(value)
+    }
+
+    /// Returns the speculative value for the given key, and tracks the access to the key.
+    fn get_value_speculative(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        let value = self.finalize_memory.get_value_speculative(program_id, mapping_name, key)?;
+        if value.is_some() {
+            self.track_keys(vec![(program_id, mapping_name, Some(key.clone()))])?;
+        }
+        Ok(value)
+    }
+}
+
 /// An in-memory committee storage.
 #[derive(Clone)]
 pub struct CommitteeMemory<N: Network> {
@@ -143,3 +858,548 @@ impl<N: Network> CommitteeStorage<N> for CommitteeMemory<N> {
         &self.storage_mode
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Returns the program ID and mapping name used by the tests.
+    fn sample_mapping() -> Result<(ProgramID<CurrentNetwork>, Identifier<CurrentNetwork>)> {
+        Ok((ProgramID::from_str("hello.aleo")?, Identifier::from_str("account")?))
+    }
+
+    /// Inserts the given keys and values into the sample mapping of the given finalize store.
+    fn insert_entries<F: FinalizeStorage<CurrentNetwork>, K: AsRef<str>, V: AsRef<str>>(
+        finalize_memory: &F,
+        entries: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<()> {
+        let (program_id, mapping_name) = sample_mapping()?;
+        for (key, value) in entries {
+            let (key, value) = (Plaintext::from_str(key.as_ref())?, Value::from_str(value.as_ref())?);
+            finalize_memory.insert_key_value(program_id, mapping_name, key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a new finalize store, with the sample mapping initialized to the given keys and values.
+    fn sample_finalize_memory(entries: &[(&str, &str)]) -> Result<FinalizeMemory<CurrentNetwork>> {
+        let (program_id, mapping_name) = sample_mapping()?;
+        let finalize_memory = FinalizeMemory::<CurrentNetwork>::open(None)?;
+        finalize_memory.initialize_mapping(program_id, mapping_name)?;
+        insert_entries(&finalize_memory, entries.iter().copied())?;
+        Ok(finalize_memory)
+    }
+
+    #[test]
+    fn test_csv_round_trip() -> Result<()> {
+        // Initialize a program ID and mapping name.
+        let (program_id, mapping_name) = sample_mapping()?;
+
+        // Initialize a finalize store with entries whose rendered form contains commas, quotes, and newlines.
+        let finalize_memory = sample_finalize_memory(&[
+            ("1field", "100u64"),
+            ("2field", "{ a: 1u8, b: [2u8, 3u8] }"),
+            ("[3u8, 4u8]", "\"hello, world\""),
+            ("{ x: 5u8, y: 6u8 }", "true"),
+        ])?;
+
+        // Export the mapping, and import it into a new store.
+        let csv = finalize_memory.export_csv(program_id, mapping_name)?;
+        assert!(csv.starts_with("key,value\n"));
+        let imported = FinalizeMemory::<CurrentNetwork>::open(None)?;
+        imported.import_csv(program_id, mapping_name, &csv)?;
+
+        // Ensure the stores are equal.
+        assert_eq!(
+            finalize_memory.get_mapping_confirmed(program_id, mapping_name)?,
+            imported.get_mapping_confirmed(program_id, mapping_name)?
+        );
+        assert_eq!(finalize_memory.get_checksum_confirmed()?, imported.get_checksum_confirmed()?);
+        // Ensure the export is stable.
+        assert_eq!(csv, imported.export_csv(program_id, mapping_name)?);
+
+        // Ensure malformed CSV is rejected.
+        assert!(imported.import_csv(program_id, mapping_name, "key,value\n1field\n").is_err());
+        assert!(imported.import_csv(program_id, mapping_name, "key,value\n\"1field,100u64\n").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_from() -> Result<()> {
+        // Initialize a program ID and mapping name.
+        let (program_id, mapping_name) = sample_mapping()?;
+
+        // Returns the value of the given key.
+        let get =
+            |finalize_memory: &FinalizeMemory<CurrentNetwork>, key: &str| -> Result<Option<Value<CurrentNetwork>>> {
+                finalize_memory.get_value_confirmed(program_id, mapping_name, &Plaintext::from_str(key)?)
+            };
+
+        // Initialize two stores that share the key `1field`, with differing values.
+        let other = sample_finalize_memory(&[("1field", "200u64"), ("3field", "300u64")])?;
+        let this = || sample_finalize_memory(&[("1field", "100u64"), ("2field", "200u64")]);
+
+        // Ensure `PreferSelf` keeps the value in this store.
+        let mut finalize_memory = this()?;
+        finalize_memory.merge_from(&other, MergePolicy::PreferSelf)?;
+        assert_eq!(get(&finalize_memory, "1field")?, Some(Value::from_str("100u64")?));
+        assert_eq!(get(&finalize_memory, "3field")?, Some(Value::from_str("300u64")?));
+        assert_eq!(finalize_memory.total_entries()?, 3);
+
+        // Ensure `PreferOther` takes the value in the other store.
+        let mut finalize_memory = this()?;
+        finalize_memory.merge_from(&other, MergePolicy::PreferOther)?;
+        assert_eq!(get(&finalize_memory, "1field")?, Some(Value::from_str("200u64")?));
+        assert_eq!(get(&finalize_memory, "3field")?, Some(Value::from_str("300u64")?));
+        assert_eq!(finalize_memory.total_entries()?, 3);
+
+        // Ensure `ErrorOnConflict` fails, and leaves this store unchanged.
+        let mut finalize_memory = this()?;
+        let checksum = finalize_memory.get_checksum_confirmed()?;
+        assert!(finalize_memory.merge_from(&other, MergePolicy::ErrorOnConflict).is_err());
+        assert_eq!(finalize_memory.get_checksum_confirmed()?, checksum);
+        assert_eq!(get(&finalize_memory, "3field")?, None);
+
+        // Ensure `ErrorOnConflict` merges stores without conflicts.
+        let mut finalize_memory = sample_finalize_memory(&[("1field", "200u64")])?;
+        finalize_memory.merge_from(&other, MergePolicy::ErrorOnConflict)?;
+        assert_eq!(finalize_memory.total_entries()?, 2);
+
+        // Ensure a merge is rejected during another atomic batch, as it could not be rolled back on its own.
+        let mut finalize_memory = this()?;
+        finalize_memory.start_atomic();
+        assert!(finalize_memory.merge_from(&other, MergePolicy::PreferOther).is_err());
+        finalize_memory.abort_atomic();
+        assert_eq!(get(&finalize_memory, "3field")?, None);
+
+        // Ensure missing mappings are initialized.
+        let mut finalize_memory = FinalizeMemory::<CurrentNetwork>::open(None)?;
+        finalize_memory.merge_from(&other, MergePolicy::ErrorOnConflict)?;
+        assert_eq!(finalize_memory.get_checksum_confirmed()?, other.get_checksum_confirmed()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_transaction() -> Result<()> {
+        // Initialize a program ID and mapping name.
+        let (program_id, mapping_name) = sample_mapping()?;
+        let key = |key: &str| Plaintext::<CurrentNetwork>::from_str(key);
+
+        // Initialize a finalize store with a single entry.
+        let mut finalize_memory = sample_finalize_memory(&[("1field", "100u64")])?;
+
+        // Run a transaction that fails midway.
+        let result = finalize_memory.transaction::<()>(|finalize_memory| {
+            finalize_memory.update_key_value(program_id, mapping_name, key("1field")?, Value::from_str("200u64")?)?;
+            finalize_memory.insert_key_value(program_id, mapping_name, key("2field")?, Value::from_str("300u64")?)?;
+            bail!("Failed midway")
+        });
+        assert!(result.is_err());
+
+        // Ensure the store is unchanged.
+        assert!(!finalize_memory.is_atomic_in_progress());
+        assert_eq!(
+            finalize_memory.get_value_speculative(program_id, mapping_name, &key("1field")?)?,
+            Some(Value::from_str("100u64")?)
+        );
+        assert!(!finalize_memory.contains_key_speculative(program_id, mapping_name, &key("2field")?)?);
+        assert_eq!(finalize_memory.total_entries()?, 1);
+
+        // Run a transaction that succeeds, and ensure its writes are committed.
+        let output = finalize_memory.transaction(|finalize_memory| {
+            finalize_memory.update_key_value(program_id, mapping_name, key("1field")?, Value::from_str("200u64")?)?;
+            finalize_memory.insert_key_value(program_id, mapping_name, key("2field")?, Value::from_str("300u64")?)?;
+            Ok(2)
+        })?;
+        assert_eq!(output, 2);
+        assert_eq!(
+            finalize_memory.get_value_confirmed(program_id, mapping_name, &key("1field")?)?,
+            Some(Value::from_str("200u64")?)
+        );
+        assert_eq!(finalize_memory.total_entries()?, 2);
+
+        // Ensure a nested transaction is rejected.
+        assert!(finalize_memory.transaction(|finalize_memory| finalize_memory.transaction(|_| Ok(()))).is_err());
+        assert!(!finalize_memory.is_atomic_in_progress());
+        Ok(())
+    }
+
+    #[test]
+    fn test_as_read_only() -> Result<()> {
+        // Initialize a program ID and mapping name.
+        let (program_id, mapping_name) = sample_mapping()?;
+
+        // Initialize a finalize store with entries.
+        let finalize_memory = sample_finalize_memory(&[("1field", "100u64"), ("2field", "200u64")])?;
+
+        // Ensure the view is consistent with the backing store.
+        let view = finalize_memory.as_read_only();
+        let key = Plaintext::from_str("1field")?;
+        assert_eq!(view.get_value(program_id, mapping_name, &key)?, Some(Value::from_str("100u64")?));
+        assert!(view.contains_key(program_id, mapping_name, &key)?);
+        assert!(!view.contains_key(program_id, mapping_name, &Plaintext::from_str("3field")?)?);
+        assert_eq!(view.keys(program_id, mapping_name)?, vec![key, Plaintext::from_str("2field")?]);
+        assert_eq!(
+            view.entries(program_id, mapping_name)?,
+            finalize_memory.get_mapping_confirmed(program_id, mapping_name)?
+        );
+
+        // Ensure the view observes later writes to the backing store.
+        insert_entries(&finalize_memory, [("3field", "300u64")])?;
+        assert_eq!(finalize_memory.as_read_only().keys(program_id, mapping_name)?.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_value_at() -> Result<()> {
+        // Initialize a program ID and mapping name.
+        let (program_id, mapping_name) = sample_mapping()?;
+        let key = Plaintext::from_str("1field")?;
+
+        // Initialize a finalize store with a key.
+        let mut finalize_memory = sample_finalize_memory(&[("1field", "100u64")])?;
+        let first = finalize_memory.checkpoint_with_id()?;
+
+        // Update the key, and checkpoint again.
+        finalize_memory.update_key_value(program_id, mapping_name, key.clone(), Value::from_str("200u64")?)?;
+        let second = finalize_memory.checkpoint_with_id()?;
+        assert!(second > first);
+
+        // Update the key once more, without a checkpoint.
+        finalize_memory.update_key_value(program_id, mapping_name, key.clone(), Value::from_str("300u64")?)?;
+
+        // Ensure each checkpoint reads the value as of that checkpoint.
+        assert_eq!(
+            finalize_memory.get_value_at(first, program_id, mapping_name, &key)?,
+            Some(Value::from_str("100u64")?)
+        );
+        assert_eq!(
+            finalize_memory.get_value_at(second, program_id, mapping_name, &key)?,
+            Some(Value::from_str("200u64")?)
+        );
+        assert_eq!(
+            finalize_memory.get_value_at(first, program_id, mapping_name, &Plaintext::from_str("2field")?)?,
+            None
+        );
+        // Ensure an unknown checkpoint is rejected.
+        assert!(finalize_memory.get_value_at(second + 1, program_id, mapping_name, &key).is_err());
+
+        // Ensure the oldest checkpoints are dropped once the bound is lowered.
+        finalize_memory.set_max_checkpoints(1)?;
+        assert!(finalize_memory.get_value_at(first, program_id, mapping_name, &key).is_err());
+        assert!(finalize_memory.get_value_at(second, program_id, mapping_name, &key).is_ok());
+        let third = finalize_memory.checkpoint_with_id()?;
+        assert!(finalize_memory.get_value_at(second, program_id, mapping_name, &key).is_err());
+        assert_eq!(
+            finalize_memory.get_value_at(third, program_id, mapping_name, &key)?,
+            Some(Value::from_str("300u64")?)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_with_proof() -> Result<()> {
+        // Initialize a program ID and mapping name.
+        let (program_id, mapping_name) = sample_mapping()?;
+        let key = |i: u64| Plaintext::from_str(&format!("{i}field"));
+
+        // Initialize a finalize store with a mapping.
+        let finalize_memory = sample_finalize_memory(&[])?;
+
+        // Ensure absence is provable in an empty mapping.
+        let root = finalize_memory.mapping_root(program_id, mapping_name)?;
+        let (value, proof) = finalize_memory.get_with_proof(program_id, mapping_name, &key(0)?)?;
+        assert!(value.is_none());
+        assert!(proof.verify(&root, program_id, mapping_name, &key(0)?, None));
+
+        // Populate the mapping.
+        insert_entries(&finalize_memory, (0..5).map(|i| (format!("{i}field"), format!("{i}u64"))))?;
+        let root = finalize_memory.mapping_root(program_id, mapping_name)?;
+
+        // Ensure a present key is proven with its value.
+        for i in 0..5 {
+            let (value, proof) = finalize_memory.get_with_proof(program_id, mapping_name, &key(i)?)?;
+            let value = value.unwrap();
+            assert_eq!(value, Value::from_str(&format!("{i}u64"))?);
+            assert!(matches!(proof, MappingProof::Membership { .. }));
+            assert!(proof.verify(&root, program_id, mapping_name, &key(i)?, Some(&value)));
+            // Ensure the proof does not verify for a different value, key, or root, or as an absence proof.
+            assert!(!proof.verify(&root, program_id, mapping_name, &key(i)?, Some(&Value::from_str("100u64")?)));
+            assert!(!proof.verify(&root, program_id, mapping_name, &key(i + 1)?, Some(&value)));
+            assert!(!proof.verify(&Field::from_u64(1), program_id, mapping_name, &key(i)?, Some(&value)));
+            assert!(!proof.verify(&root, program_id, mapping_name, &key(i)?, None));
+        }
+
+        // Ensure a missing key is proven absent.
+        for i in 5..10 {
+            let (value, proof) = finalize_memory.get_with_proof(program_id, mapping_name, &key(i)?)?;
+            assert!(value.is_none());
+            assert!(matches!(proof, MappingProof::NonMembership { .. }));
+            assert!(proof.verify(&root, program_id, mapping_name, &key(i)?, None));
+            // Ensure the absence proof does not verify for a present key, or against a different root.
+            assert!(!proof.verify(&root, program_id, mapping_name, &key(0)?, None));
+            assert!(!proof.verify(&Field::from_u64(1), program_id, mapping_name, &key(i)?, None));
+        }
+
+        // Ensure a proof is invalidated once the mapping changes.
+        let (value, proof) = finalize_memory.get_with_proof(program_id, mapping_name, &key(0)?)?;
+        finalize_memory.update_key_value(program_id, mapping_name, key(0)?, Value::from_str("100u64")?)?;
+        let new_root = finalize_memory.mapping_root(program_id, mapping_name)?;
+        assert!(!proof.verify(&new_root, program_id, mapping_name, &key(0)?, value.as_ref()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_shrink_bookkeeping() -> Result<()> {
+        // Initialize a program ID and mapping name.
+        let (program_id, mapping_name) = sample_mapping()?;
+        let key = |i: u64| Plaintext::from_str(&format!("{i}field"));
+
+        // Initialize a bounded finalize store, and churn through its entries.
+        let mut bounded = BoundedFinalizeMemory::<CurrentNetwork>::open_bounded(None, 1000)?;
+        bounded.initialize_mapping(program_id, mapping_name)?;
+        insert_entries(&bounded, (0..100).map(|i| (format!("{i}field"), format!("{i}u64"))))?;
+        for i in 0..90 {
+            bounded.remove_key_value(program_id, mapping_name, &key(i)?)?;
+        }
+        let entries = bounded.get_mapping_confirmed(program_id, mapping_name)?;
+        assert_eq!(entries.len(), 10);
+
+        // Returns the number of tracked keys, and the capacity of the tracking map.
+        let tracking = |bounded: &BoundedFinalizeMemory<CurrentNetwork>| {
+            let access_order = bounded.access_order.lock();
+            (access_order.len(), access_order.capacity())
+        };
+        let (num_tracked, capacity) = tracking(&bounded);
+        assert_eq!(num_tracked, 10);
+        assert!(capacity >= 100);
+
+        // Shrink the bookkeeping of the store.
+        bounded.shrink_bookkeeping()?;
+
+        // Ensure the contents and their order are preserved.
+        assert_eq!(bounded.get_mapping_confirmed(program_id, mapping_name)?, entries);
+        // Ensure the live keys are still tracked, and the capacity shrinks.
+        let (num_tracked, capacity) = tracking(&bounded);
+        assert_eq!(num_tracked, 10);
+        assert!(capacity < 100);
+
+        // Initialize a finalize store, and retain a full set of checkpoints.
+        let mut finalize_memory = sample_finalize_memory(&[("1field", "100u64"), ("2field", "200u64")])?;
+        let entries = finalize_memory.get_mapping_confirmed(program_id, mapping_name)?;
+        for _ in 0..DEFAULT_MAX_CHECKPOINTS {
+            finalize_memory.checkpoint_with_id()?;
+        }
+        // Lower the maximum number of checkpoints.
+        finalize_memory.set_max_checkpoints(1)?;
+        let checkpoint_capacity =
+            |finalize_memory: &FinalizeMemory<CurrentNetwork>| finalize_memory.checkpoints.lock().snapshots.capacity();
+        assert!(checkpoint_capacity(&finalize_memory) >= DEFAULT_MAX_CHECKPOINTS);
+
+        // Ensure the capacity of the retained snapshots shrinks.
+        finalize_memory.shrink_bookkeeping()?;
+        assert!(checkpoint_capacity(&finalize_memory) < DEFAULT_MAX_CHECKPOINTS);
+        assert_eq!(finalize_memory.get_mapping_confirmed(program_id, mapping_name)?, entries);
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_bounded() -> Result<()> {
+        // Initialize a program ID and mapping name.
+        let (program_id, mapping_name) = sample_mapping()?;
+        let key = |i: u64| Plaintext::from_str(&format!("{i}field"));
+
+        // Initialize a finalize store bounded to 3 entries.
+        assert!(BoundedFinalizeMemory::<CurrentNetwork>::open_bounded(None, 0).is_err());
+        let finalize_memory = BoundedFinalizeMemory::<CurrentNetwork>::open_bounded(None, 3)?;
+        finalize_memory.initialize_mapping(program_id, mapping_name)?;
+
+        // Fill the store past the bound.
+        for i in 0..5 {
+            finalize_memory.insert_key_value(
+                program_id,
+                mapping_name,
+                key(i)?,
+                Value::from_str(&format!("{i}u64"))?,
+            )?;
+            assert_eq!(finalize_memory.total_entries()?, (i as usize + 1).min(3));
+        }
+        // Ensure the oldest entries are evicted, and the newest entries are retained.
+        for i in 0..2 {
+            assert!(!finalize_memory.contains_key_confirmed(program_id, mapping_name, &key(i)?)?);
+        }
+        for i in 2..5 {
+            assert!(finalize_memory.contains_key_confirmed(program_id, mapping_name, &key(i)?)?);
+        }
+
+        // Read the oldest retained entry, and insert a new entry.
+        assert!(finalize_memory.get_value_confirmed(program_id, mapping_name, &key(2)?)?.is_some());
+        finalize_memory.insert_key_value(program_id, mapping_name, key(5)?, Value::from_str("5u64")?)?;
+        // Ensure the least-recently-used entry is evicted instead of the one that was read.
+        assert!(finalize_memory.contains_key_confirmed(program_id, mapping_name, &key(2)?)?);
+        assert!(!finalize_memory.contains_key_confirmed(program_id, mapping_name, &key(3)?)?);
+        assert_eq!(finalize_memory.total_entries()?, 3);
+
+        // Ensure an aborted batch neither evicts entries, nor changes the tracked keys.
+        finalize_memory.start_atomic();
+        finalize_memory.insert_key_value(program_id, mapping_name, key(6)?, Value::from_str("6u64")?)?;
+        finalize_memory.abort_atomic();
+        for i in [2, 4, 5] {
+            assert!(finalize_memory.contains_key_confirmed(program_id, mapping_name, &key(i)?)?);
+        }
+        assert!(!finalize_memory.contains_key_confirmed(program_id, mapping_name, &key(6)?)?);
+        assert_eq!(finalize_memory.total_entries()?, 3);
+
+        // Ensure a finished batch evicts the least-recently-used entry once it is committed.
+        finalize_memory.start_atomic();
+        finalize_memory.insert_key_value(program_id, mapping_name, key(6)?, Value::from_str("6u64")?)?;
+        assert!(finalize_memory.contains_key_confirmed(program_id, mapping_name, &key(4)?)?);
+        finalize_memory.finish_atomic()?;
+        assert!(!finalize_memory.contains_key_confirmed(program_id, mapping_name, &key(4)?)?);
+        assert_eq!(finalize_memory.total_entries()?, 3);
+
+        // Ensure a removed entry is untracked, so that it does not count towards the bound.
+        finalize_memory.remove_key_value(program_id, mapping_name, &key(2)?)?;
+        finalize_memory.insert_key_value(program_id, mapping_name, key(7)?, Value::from_str("7u64")?)?;
+        for i in [5, 6, 7] {
+            assert!(finalize_memory.contains_key_confirmed(program_id, mapping_name, &key(i)?)?);
+        }
+        assert_eq!(finalize_memory.total_entries()?, 3);
+
+        // Ensure an unbounded store does not evict.
+        let unbounded = sample_finalize_memory(&[])?;
+        insert_entries(&unbounded, (0..5).map(|i| (format!("{i}field"), format!("{i}u64"))))?;
+        assert_eq!(unbounded.total_entries()?, 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_or_insert_with() -> Result<()> {
+        // Initialize a program ID and mapping name.
+        let (program_id, mapping_name) = sample_mapping()?;
+        let key = Plaintext::<CurrentNetwork>::from_str("1field")?;
+
+        // Initialize a finalize store with an empty mapping.
+        let mut finalize_memory = sample_finalize_memory(&[])?;
+
+        // Ensure the default is inserted on the first call.
+        let mut calls = 0;
+        let value = finalize_memory.get_or_insert_with(program_id, mapping_name, key.clone(), || {
+            calls += 1;
+            Value::from_str("100u64").unwrap()
+        })?;
+        assert_eq!(value, Value::from_str("100u64")?);
+        assert_eq!(finalize_memory.get_value_confirmed(program_id, mapping_name, &key)?, Some(value));
+
+        // Ensure the stored value is returned on the second call, without evaluating the default.
+        let value = finalize_memory.get_or_insert_with(program_id, mapping_name, key.clone(), || {
+            calls += 1;
+            Value::from_str("200u64").unwrap()
+        })?;
+        assert_eq!(value, Value::from_str("100u64")?);
+        assert_eq!(calls, 1);
+        assert_eq!(finalize_memory.total_entries()?, 1);
+
+        // Ensure an uninitialized mapping is rejected.
+        let unknown = Identifier::from_str("unknown")?;
+        let default = || Value::from_str("100u64").unwrap();
+        assert!(finalize_memory.get_or_insert_with(program_id, unknown, key, default).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_values() -> Result<()> {
+        // Initialize a program ID and mapping name.
+        let (program_id, mapping_name) = sample_mapping()?;
+
+        // Initialize a finalize store with several entries, some of which share a value.
+        let finalize_memory = sample_finalize_memory(&[
+            ("3field", "100u64"),
+            ("2field", "200u64"),
+            ("1field", "100u64"),
+            ("4field", "300u64"),
+        ])?;
+
+        // Returns the given keys, ordered by their key IDs.
+        let by_key_id = |keys: &[&str]| -> Result<Vec<Plaintext<CurrentNetwork>>> {
+            let mut keys = keys.iter().map(|key| Plaintext::from_str(key)).collect::<Result<Vec<_>>>()?;
+            keys.sort_by_key(|key| to_key_id(&program_id, &mapping_name, key).unwrap());
+            Ok(keys)
+        };
+
+        // Ensure only the entries with the given value are returned, in key ID order.
+        let target = Value::from_str("100u64")?;
+        let entries = finalize_memory.filter_values(program_id, mapping_name, |value| *value == target)?;
+        let expected = by_key_id(&["3field", "1field"])?.into_iter().map(|key| (key, target.clone())).collect_vec();
+        assert_eq!(entries, expected);
+
+        // Ensure the order does not depend on the order in which the keys were written.
+        finalize_memory.update_key_value(program_id, mapping_name, Plaintext::from_str("4field")?, target.clone())?;
+        finalize_memory.remove_key_value(program_id, mapping_name, &Plaintext::from_str("3field")?)?;
+        finalize_memory.insert_key_value(program_id, mapping_name, Plaintext::from_str("3field")?, target.clone())?;
+        let keys = finalize_memory
+            .filter_values(program_id, mapping_name, |value| *value == target)?
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect_vec();
+        assert_eq!(keys, by_key_id(&["1field", "3field", "4field"])?);
+
+        // Ensure a predicate that matches nothing returns no entries.
+        assert!(finalize_memory.filter_values(program_id, mapping_name, |_| false)?.is_empty());
+        // Ensure an uninitialized mapping is rejected.
+        assert!(finalize_memory.filter_values(program_id, Identifier::from_str("unknown")?, |_| true).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_mapping_capacity() -> Result<()> {
+        // Initialize a program ID and mapping name.
+        let (program_id, mapping_name) = sample_mapping()?;
+        let key = |i: u64| Plaintext::from_str(&format!("{i}field"));
+
+        // Initialize a finalize store that caps each mapping to 3 entries.
+        let mut finalize_memory = BoundedFinalizeMemory::<CurrentNetwork>::open_bounded(None, 1000)?;
+        assert!(finalize_memory.set_max_mapping_entries(0).is_err());
+        assert!(finalize_memory.set_max_mapping_entries(CurrentNetwork::MAX_MAPPING_ENTRIES + 1).is_err());
+        finalize_memory.set_max_mapping_entries(3)?;
+        finalize_memory.initialize_mapping(program_id, mapping_name)?;
+
+        // Fill the mapping up to the cap.
+        insert_entries(&finalize_memory, (0..3).map(|i| (format!("{i}field"), "1u64")))?;
+        // Ensure a new key past the cap is rejected, while an existing key can still be updated.
+        assert!(finalize_memory.insert_key_value(program_id, mapping_name, key(3)?, Value::from_str("1u64")?).is_err());
+        assert!(finalize_memory.update_key_value(program_id, mapping_name, key(3)?, Value::from_str("1u64")?).is_err());
+        finalize_memory.update_key_value(program_id, mapping_name, key(0)?, Value::from_str("2u64")?)?;
+        assert_eq!(finalize_memory.total_entries()?, 3);
+
+        // Ensure the entries pending in an atomic batch count towards the cap.
+        finalize_memory.remove_key_value(program_id, mapping_name, &key(0)?)?;
+        finalize_memory.start_atomic();
+        finalize_memory.insert_key_value(program_id, mapping_name, key(3)?, Value::from_str("1u64")?)?;
+        assert!(finalize_memory.insert_key_value(program_id, mapping_name, key(4)?, Value::from_str("1u64")?).is_err());
+        finalize_memory.finish_atomic()?;
+        assert_eq!(finalize_memory.total_entries()?, 3);
+
+        // Ensure a key removed in an atomic batch frees its capacity, until the batch is rewound.
+        finalize_memory.start_atomic();
+        finalize_memory.remove_key_value(program_id, mapping_name, &key(1)?)?;
+        finalize_memory.atomic_checkpoint();
+        finalize_memory.insert_key_value(program_id, mapping_name, key(4)?, Value::from_str("1u64")?)?;
+        assert!(finalize_memory.insert_key_value(program_id, mapping_name, key(5)?, Value::from_str("1u64")?).is_err());
+        finalize_memory.atomic_rewind();
+        finalize_memory.insert_key_value(program_id, mapping_name, key(5)?, Value::from_str("1u64")?)?;
+        assert!(finalize_memory.insert_key_value(program_id, mapping_name, key(4)?, Value::from_str("1u64")?).is_err());
+        finalize_memory.finish_atomic()?;
+        assert!(!finalize_memory.contains_key_confirmed(program_id, mapping_name, &key(4)?)?);
+        assert_eq!(finalize_memory.total_entries()?, 3);
+
+        // Ensure the cap applies to each mapping separately.
+        let other_mapping = Identifier::from_str("other")?;
+        finalize_memory.initialize_mapping(program_id, other_mapping)?;
+        finalize_memory.insert_key_value(program_id, other_mapping, key(0)?, Value::from_str("1u64")?)?;
+        Ok(())
+    }
+}
@@ -44,7 +44,7 @@ fn to_mapping_id<N: Network>(program_id: &ProgramID<N>, mapping_name: &Identifie
 }
 
 /// Returns the key ID for the given `program ID`, `mapping name`, and `key`.
-fn to_key_id<N: Network>(
+pub(crate) fn to_key_id<N: Network>(
     program_id: &ProgramID<N>,
     mapping_name: &Identifier<N>,
     key: &Plaintext<N>,
@@ -60,6 +60,12 @@ fn to_key_id<N: Network>(
     N::hash_bhp1024(&preimage)
 }
 
+/// Returns the value ID for the given `key ID` and `value`.
+pub(crate) fn to_value_id<N: Network>(key_id: &Field<N>, value: &Value<N>) -> Result<Field<N>> {
+    // Compute the value ID as `Hash( key ID || Hash( value ) )`.
+    N::hash_bhp1024(&(*key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())
+}
+
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
 /// We define the `key ID := Hash ( program ID || mapping name || Hash(key) )`
@@ -202,7 +208,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, &key)?;
         // Compute the value ID.
-        let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+        let value_id = to_value_id(&key_id, &value)?;
 
         atomic_batch_scope!(self, {
             // Update the key-value map with the new key-value.
@@ -234,7 +240,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, &key)?;
         // Compute the value ID.
-        let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+        let value_id = to_value_id(&key_id, &value)?;
 
         atomic_batch_scope!(self, {
             // Update the key-value map with the new key-value.
@@ -1268,6 +1274,61 @@ mod tests {
         check_initialize_update_remove(&finalize_store, program_id, mapping_name);
     }
 
+    #[test]
+    fn test_sharded_checksum() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_names = [Identifier::from_str("account").unwrap(), Identifier::from_str("balance").unwrap()];
+
+        // Initialize an unsharded and a sharded finalize store.
+        let unsharded = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        let sharded = FinalizeStore::from(FinalizeMemory::open_sharded(None, 4).unwrap()).unwrap();
+        // Ensure a sharded store requires at least one shard.
+        assert!(FinalizeMemory::<CurrentNetwork>::open_sharded(None, 0).is_err());
+
+        // Check the basic operations on a sharded store.
+        let finalize_store = FinalizeStore::from(FinalizeMemory::open_sharded(None, 4).unwrap()).unwrap();
+        check_initialize_insert_remove(&finalize_store, program_id, mapping_names[0]);
+
+        for finalize_store in [&unsharded, &sharded] {
+            // Initialize the mappings.
+            for mapping_name in mapping_names {
+                finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+            }
+        }
+
+        // Insert, update, and remove the same key-value pairs in both stores.
+        for i in 0..100u64 {
+            let mapping_name = mapping_names[i as usize % mapping_names.len()];
+            let key = Plaintext::from(Literal::Field(Field::from_u64(i)));
+            let value = Value::from(Literal::U64(U64::new(rng.gen())));
+
+            for finalize_store in [&unsharded, &sharded] {
+                finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value.clone()).unwrap();
+                if i % 3 == 0 {
+                    finalize_store
+                        .update_key_value(program_id, mapping_name, key.clone(), Value::from_str("0u64").unwrap())
+                        .unwrap();
+                }
+                if i % 7 == 0 {
+                    finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap();
+                }
+            }
+        }
+
+        // Ensure both stores contain the same mappings.
+        for mapping_name in mapping_names {
+            assert_eq!(
+                unsharded.get_mapping_confirmed(program_id, mapping_name).unwrap(),
+                sharded.get_mapping_confirmed(program_id, mapping_name).unwrap()
+            );
+        }
+        // Ensure both stores produce the same checksum.
+        assert_eq!(unsharded.get_checksum_confirmed().unwrap(), sharded.get_checksum_confirmed().unwrap());
+    }
+
     /// If you want to customize the DB size, run:
     /// ```ignore
     /// NUM_ITEMS=100000 cargo test test_finalize_timings -- --nocapture
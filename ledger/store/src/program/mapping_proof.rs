@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{to_key_id, to_value_id};
+use console::{
+    collections::merkle_tree::MerklePath,
+    network::{prelude::*, PoseidonMerkleTree},
+    program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
+};
+
+/// The depth of the Merkle tree over the entries of a mapping.
+pub const MAPPING_DEPTH: u8 = 32;
+
+/// A leaf of a mapping tree, consisting of the key ID and value ID of an entry, and its Merkle path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappingLeaf<N: Network> {
+    /// The key ID of the entry.
+    key_id: Field<N>,
+    /// The value ID of the entry.
+    value_id: Field<N>,
+    /// The Merkle path of the leaf.
+    path: MerklePath<N, MAPPING_DEPTH>,
+}
+
+/// A proof that a key is, or is not, in a mapping.
+///
+/// The mapping tree is a Merkle tree over the `(key ID, value ID)` entries of the mapping, sorted by key ID,
+/// and the mapping root is `Hash( tree root || number of entries )`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MappingProof<N: Network> {
+    /// The key is in the mapping, at the given leaf.
+    Membership { tree_root: Field<N>, num_leaves: u64, leaf: MappingLeaf<N> },
+    /// The key is not in the mapping, and falls between the given adjacent leaves.
+    /// The lower leaf is absent if the key precedes every entry,
+    /// and the upper leaf is absent if the key follows every entry.
+    NonMembership { tree_root: Field<N>, num_leaves: u64, lower: Option<MappingLeaf<N>>, upper: Option<MappingLeaf<N>> },
+}
+
+impl<N: Network> MappingProof<N> {
+    /// Returns the mapping root of the given mapping entries.
+    /// Note: This hashes every entry and rebuilds the mapping tree, at a cost linear in the number of entries.
+    pub fn mapping_root(
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        entries: &[(Plaintext<N>, Value<N>)],
+    ) -> Result<Field<N>> {
+        let (leaves, tree) = mapping_tree(program_id, mapping_name, entries)?;
+        to_mapping_root(*tree.root(), leaves.len() as u64)
+    }
+
+    /// Returns the mapping root of the given mapping entries,
+    /// and a proof that the given key is, or is not, in the mapping.
+    /// Note: This hashes every entry and rebuilds the mapping tree, at a cost linear in the number of entries.
+    pub fn prove(
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        entries: &[(Plaintext<N>, Value<N>)],
+        key: &Plaintext<N>,
+    ) -> Result<(Field<N>, Self)> {
+        // Construct the mapping tree.
+        let (leaves, tree) = mapping_tree(program_id, mapping_name, entries)?;
+        let tree_root = *tree.root();
+        let num_leaves = leaves.len() as u64;
+
+        // A helper to prove the leaf at the given index.
+        let prove_leaf = |index: usize| -> Result<MappingLeaf<N>> {
+            let (key_id, value_id) = leaves[index];
+            Ok(MappingLeaf { key_id, value_id, path: tree.prove(index, &vec![key_id, value_id])? })
+        };
+
+        // Locate the key in the sorted leaves.
+        let key_id = to_key_id(&program_id, &mapping_name, key)?;
+        let proof = match leaves.binary_search_by(|(leaf_key_id, _)| leaf_key_id.cmp(&key_id)) {
+            // If the key is present, prove its leaf.
+            Ok(index) => Self::Membership { tree_root, num_leaves, leaf: prove_leaf(index)? },
+            // If the key is absent, prove the leaves on either side of where it would be.
+            Err(index) => Self::NonMembership {
+                tree_root,
+                num_leaves,
+                lower: index.checked_sub(1).map(prove_leaf).transpose()?,
+                upper: (index < leaves.len()).then(|| prove_leaf(index)).transpose()?,
+            },
+        };
+        Ok((to_mapping_root(tree_root, num_leaves)?, proof))
+    }
+
+    /// Returns `true` if the proof shows that the given key maps to the given value in the mapping with the given root,
+    /// or, if the value is `None`, that the given key is not in the mapping.
+    pub fn verify(
+        &self,
+        root: &Field<N>,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        value: Option<&Value<N>>,
+    ) -> bool {
+        self.check(root, program_id, mapping_name, key, value).unwrap_or(false)
+    }
+
+    /// Returns `true` if the proof is valid, as defined in `verify`.
+    fn check(
+        &self,
+        root: &Field<N>,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        value: Option<&Value<N>>,
+    ) -> Result<bool> {
+        // Compute the key ID.
+        let key_id = to_key_id(&program_id, &mapping_name, key)?;
+        // A helper to check that a leaf is in the mapping tree.
+        let is_valid_leaf = |tree_root: &Field<N>, num_leaves: u64, leaf: &MappingLeaf<N>| {
+            *leaf.path.leaf_index() < num_leaves
+                && N::verify_merkle_path_psd(&leaf.path, tree_root, &vec![leaf.key_id, leaf.value_id])
+        };
+
+        match (self, value) {
+            (Self::Membership { tree_root, num_leaves, leaf }, Some(value)) => {
+                // Ensure the tree matches the mapping root.
+                Ok(to_mapping_root(*tree_root, *num_leaves)? == *root
+                    // Ensure the leaf is for the given key and value.
+                    && leaf.key_id == key_id
+                    && leaf.value_id == to_value_id(&key_id, value)?
+                    // Ensure the leaf is in the tree.
+                    && is_valid_leaf(tree_root, *num_leaves, leaf))
+            }
+            (Self::NonMembership { tree_root, num_leaves, lower, upper }, None) => {
+                // Ensure the tree matches the mapping root.
+                if to_mapping_root(*tree_root, *num_leaves)? != *root {
+                    return Ok(false);
+                }
+                // Ensure the neighbouring leaves are in the tree, and on either side of the key.
+                if let Some(lower) = lower {
+                    if lower.key_id >= key_id || !is_valid_leaf(tree_root, *num_leaves, lower) {
+                        return Ok(false);
+                    }
+                }
+                if let Some(upper) = upper {
+                    if upper.key_id <= key_id || !is_valid_leaf(tree_root, *num_leaves, upper) {
+                        return Ok(false);
+                    }
+                }
+                // Ensure the neighbouring leaves are adjacent, so that no entry lies between them.
+                let index = |leaf: &MappingLeaf<N>| *leaf.path.leaf_index();
+                Ok(match (lower, upper) {
+                    (Some(lower), Some(upper)) => index(lower).checked_add(1) == Some(index(upper)),
+                    (Some(lower), None) => index(lower).checked_add(1) == Some(*num_leaves),
+                    (None, Some(upper)) => index(upper) == 0,
+                    (None, None) => *num_leaves == 0,
+                })
+            }
+            // The proof does not match the claimed presence of the key.
+            _ => Ok(false),
+        }
+    }
+}
+
+/// Returns the `(key ID, value ID)` leaves of the given mapping entries, sorted by key ID, and their Merkle tree.
+fn mapping_tree<N: Network>(
+    program_id: ProgramID<N>,
+    mapping_name: Identifier<N>,
+    entries: &[(Plaintext<N>, Value<N>)],
+) -> Result<(Vec<(Field<N>, Field<N>)>, PoseidonMerkleTree<N, MAPPING_DEPTH>)> {
+    // Compute the leaves, sorted by key ID.
+    let mut leaves = entries
+        .iter()
+        .map(|(key, value)| {
+            let key_id = to_key_id(&program_id, &mapping_name, key)?;
+            Ok((key_id, to_value_id(&key_id, value)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    leaves.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    // Construct the Merkle tree.
+    let tree =
+        N::merkle_tree_psd(&leaves.iter().map(|(key_id, value_id)| vec![*key_id, *value_id]).collect::<Vec<_>>())?;
+    Ok((leaves, tree))
+}
+
+/// Returns the mapping root for the given tree root and number of leaves.
+fn to_mapping_root<N: Network>(tree_root: Field<N>, num_leaves: u64) -> Result<Field<N>> {
+    N::hash_psd2(&[tree_root, Field::from_u64(num_leaves)])
+}
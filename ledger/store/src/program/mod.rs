@@ -17,3 +17,6 @@ pub use committee::*;
 
 mod finalize;
 pub use finalize::*;
+
+mod mapping_proof;
+pub use mapping_proof::*;
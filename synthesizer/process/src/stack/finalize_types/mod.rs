@@ -90,6 +90,11 @@ impl<N: Network> FinalizeTypes<N> {
         self.inputs.contains_key(&register.locator())
     }
 
+    /// Returns the locators and types of all registers, with the input registers first.
+    pub(super) fn registers(&self) -> impl '_ + Iterator<Item = (&u64, &FinalizeType<N>)> {
+        self.inputs.iter().chain(self.destinations.iter())
+    }
+
     /// Returns the type of the given operand.
     pub fn get_type_from_operand(
         &self,
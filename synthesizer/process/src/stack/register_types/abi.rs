@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::ProgramID;
+
+use serde_json::json;
+
+/// The version of the ABI document format, which is bumped on any incompatible change to its layout.
+const ABI_VERSION: u32 = 1;
+
+/// The struct and record definitions referenced by an ABI, keyed by their program ID and name.
+struct Definitions<N: Network> {
+    /// The struct definitions.
+    structs: IndexMap<(ProgramID<N>, Identifier<N>), serde_json::Value>,
+    /// The record definitions.
+    records: IndexMap<(ProgramID<N>, Identifier<N>), serde_json::Value>,
+}
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns the ABI document for the given closure, whose register types are `self`.
+    /// The document lists the closure inputs and outputs by type, along with the definitions of every struct
+    /// and record they reference, including nested and external definitions, so that it is self-contained.
+    /// Unlike the `Display` and `Debug` forms, the layout is versioned by `abi_version`, for use in code generation.
+    pub fn to_abi(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        closure: &Closure<N>,
+    ) -> Result<serde_json::Value> {
+        // Ensure the register types correspond to the closure.
+        ensure!(
+            self.inputs.len() == closure.inputs().len(),
+            "The register types do not match the inputs of closure '{}'",
+            closure.name()
+        );
+
+        // Initialize the referenced definitions.
+        let mut definitions = Definitions { structs: IndexMap::new(), records: IndexMap::new() };
+        // Describe the inputs.
+        let inputs = closure
+            .inputs()
+            .iter()
+            .map(|input| {
+                let register_type = self.get_type(stack, input.register())?;
+                define_register_type(stack, &register_type, &mut definitions)?;
+                Ok(json!({ "register": input.register().to_string(), "type": register_type.to_string() }))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Describe the outputs.
+        let outputs = closure
+            .outputs()
+            .iter()
+            .map(|output| {
+                define_register_type(stack, output.register_type(), &mut definitions)?;
+                Ok(json!({ "type": output.register_type().to_string() }))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(json!({
+            "abi_version": ABI_VERSION,
+            "program": stack.program_id().to_string(),
+            "closure": closure.name().to_string(),
+            "inputs": inputs,
+            "outputs": outputs,
+            "structs": definitions.structs.into_values().collect::<Vec<_>>(),
+            "records": definitions.records.into_values().collect::<Vec<_>>(),
+        }))
+    }
+}
+
+/// Adds the definitions referenced by the given register type.
+fn define_register_type<N: Network>(
+    stack: &(impl StackMatches<N> + StackProgram<N>),
+    register_type: &RegisterType<N>,
+    definitions: &mut Definitions<N>,
+) -> Result<()> {
+    match register_type {
+        RegisterType::Plaintext(plaintext_type) => define_plaintext_type(stack.program(), plaintext_type, definitions),
+        RegisterType::Record(record_name) => {
+            define_record_type(stack.program(), stack.program().get_record(record_name)?, definitions)
+        }
+        RegisterType::ExternalRecord(locator) => {
+            let external = stack.get_external_program(locator.program_id())?;
+            define_record_type(external, external.get_record(locator.resource())?, definitions)
+        }
+        RegisterType::Future(locator) => bail!("Future '{locator}' cannot be described in an ABI"),
+    }
+}
+
+/// Adds the definitions referenced by the given plaintext type, resolving structs in the given program.
+fn define_plaintext_type<N: Network>(
+    program: &Program<N>,
+    plaintext_type: &PlaintextType<N>,
+    definitions: &mut Definitions<N>,
+) -> Result<()> {
+    match plaintext_type {
+        PlaintextType::Literal(..) => Ok(()),
+        PlaintextType::Struct(struct_name) => {
+            let key = (*program.id(), *struct_name);
+            if !definitions.structs.contains_key(&key) {
+                // Define the members first, so that every definition follows the definitions it depends on.
+                let struct_type = program.get_struct(struct_name)?;
+                let mut members = Vec::with_capacity(struct_type.members().len());
+                for (member_name, member_type) in struct_type.members() {
+                    define_plaintext_type(program, member_type, definitions)?;
+                    members.push(json!({ "name": member_name.to_string(), "type": member_type.to_string() }));
+                }
+                definitions.structs.insert(
+                    key,
+                    json!({ "program": program.id().to_string(), "name": struct_name.to_string(), "members": members }),
+                );
+            }
+            Ok(())
+        }
+        PlaintextType::Array(array_type) => define_plaintext_type(program, array_type.base_element_type(), definitions),
+    }
+}
+
+/// Adds the definition of the given record type, and the definitions it references.
+/// The structs are resolved in the given program.
+fn define_record_type<N: Network>(
+    program: &Program<N>,
+    record_type: &RecordType<N>,
+    definitions: &mut Definitions<N>,
+) -> Result<()> {
+    let key = (*program.id(), *record_type.name());
+    if !definitions.records.contains_key(&key) {
+        // Define the entries.
+        let mut entries = Vec::with_capacity(record_type.entries().len());
+        for (entry_name, entry_type) in record_type.entries() {
+            define_plaintext_type(program, entry_type.plaintext_type(), definitions)?;
+            entries.push(json!({ "name": entry_name.to_string(), "type": entry_type.to_string() }));
+        }
+        let owner = match record_type.owner().is_public() {
+            true => "address.public",
+            false => "address.private",
+        };
+        definitions.records.insert(
+            key,
+            json!({
+                "program": program.id().to_string(),
+                "name": record_type.name().to_string(),
+                "owner": owner,
+                "entries": entries,
+            }),
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::register_types::test_helpers::{sample_closure, sample_stack_with_placeholder};
+
+    #[test]
+    fn test_to_abi() -> Result<()> {
+        // Initialize a stack with a closure that takes a struct input, which nests another struct.
+        let stack = sample_stack_with_placeholder(
+            r"
+program abi.aleo;
+
+struct point:
+    x as u32;
+    y as field;
+
+struct segment:
+    start as point;
+    end as point;
+
+closure foo:
+    input r0 as segment;
+    input r1 as u64;
+    add r0.start.x 1u32 into r2;
+    output r2 as u32;
+    output r0.end as point;",
+        )?;
+
+        // Retrieve the closure and its register types.
+        let (closure, register_types) = sample_closure(&stack, "foo")?;
+
+        // Ensure the ABI lists the inputs, outputs, and referenced definitions, in dependency order.
+        let expected = json!({
+            "abi_version": 1,
+            "program": "abi.aleo",
+            "closure": "foo",
+            "inputs": [{ "register": "r0", "type": "segment" }, { "register": "r1", "type": "u64" }],
+            "outputs": [{ "type": "u32" }, { "type": "point" }],
+            "structs": [
+                {
+                    "program": "abi.aleo",
+                    "name": "point",
+                    "members": [{ "name": "x", "type": "u32" }, { "name": "y", "type": "field" }],
+                },
+                {
+                    "program": "abi.aleo",
+                    "name": "segment",
+                    "members": [{ "name": "start", "type": "point" }, { "name": "end", "type": "point" }],
+                },
+            ],
+            "records": [],
+        });
+        assert_eq!(register_types.to_abi(&stack, &closure)?, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,232 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The access from a node in an access tree to one of its children.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccessEdge<N: Network> {
+    /// An access to the given member of a struct or record.
+    Member(Identifier<N>),
+    /// An access to any element of an array, as every element shares the same type.
+    Element,
+}
+
+/// A node in the access tree of a register, returned by `RegisterTypes::access_tree`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccessNode<N: Network> {
+    /// The access from the parent node, or `None` for the root register.
+    access: Option<AccessEdge<N>>,
+    /// The type of the node.
+    register_type: RegisterType<N>,
+    /// The child nodes, in declaration order.
+    children: Vec<AccessNode<N>>,
+}
+
+impl<N: Network> AccessNode<N> {
+    /// Returns the access from the parent node, or `None` for the root register.
+    pub const fn access(&self) -> Option<&AccessEdge<N>> {
+        self.access.as_ref()
+    }
+
+    /// Returns the type of the node.
+    pub const fn register_type(&self) -> &RegisterType<N> {
+        &self.register_type
+    }
+
+    /// Returns the child nodes, in declaration order.
+    pub fn children(&self) -> &[AccessNode<N>] {
+        &self.children
+    }
+}
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns the tree of accesses into the given register, where each node holds its member or index and its type.
+    /// Records and structs are expanded into their members, and arrays into a single node for all of their elements,
+    /// up to `N::MAX_DATA_DEPTH` levels below the register.
+    pub fn access_tree(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        register: &Register<N>,
+    ) -> Result<AccessNode<N>> {
+        expand(stack, stack.program(), None, self.get_type(stack, register)?, 0)
+    }
+}
+
+/// Returns the access node for the given type, expanding its children until the maximum depth.
+/// The structs and records of the type are resolved in the given program.
+fn expand<'a, N: Network>(
+    stack: &'a (impl StackMatches<N> + StackProgram<N>),
+    program: &'a Program<N>,
+    access: Option<AccessEdge<N>>,
+    register_type: RegisterType<N>,
+    depth: usize,
+) -> Result<AccessNode<N>> {
+    // Returns the accesses and types of the owner and entries of the given record.
+    let record_children = |record: &RecordType<N>| -> Result<Vec<(AccessEdge<N>, RegisterType<N>)>> {
+        let owner = (
+            AccessEdge::Member(Identifier::from_str("owner")?),
+            RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)),
+        );
+        let entries = record.entries().iter().map(|(name, entry_type)| {
+            (AccessEdge::Member(*name), RegisterType::Plaintext(entry_type.plaintext_type().clone()))
+        });
+        Ok(std::iter::once(owner).chain(entries).collect())
+    };
+
+    // Collect the program that defines the children, along with their accesses and types.
+    // The children are not collected once the maximum depth is reached.
+    let (program, children) = match &register_type {
+        _ if depth >= N::MAX_DATA_DEPTH => (program, vec![]),
+        RegisterType::Plaintext(PlaintextType::Literal(..)) | RegisterType::Future(..) => (program, vec![]),
+        RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
+            let members = program.get_struct(struct_name)?.members().iter();
+            let children = members
+                .map(|(name, member_type)| (AccessEdge::Member(*name), RegisterType::Plaintext(member_type.clone())));
+            (program, children.collect())
+        }
+        RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+            (program, vec![(AccessEdge::Element, RegisterType::Plaintext(array_type.next_element_type().clone()))])
+        }
+        RegisterType::Record(record_name) => (program, record_children(program.get_record(record_name)?)?),
+        RegisterType::ExternalRecord(locator) => {
+            // Resolve the record, and the structs of its entries, in the external program.
+            let external = stack.get_external_program(locator.program_id())?;
+            (external, record_children(external.get_record(locator.resource())?)?)
+        }
+    };
+
+    // Expand the children.
+    let children = children
+        .into_iter()
+        .map(|(access, register_type)| expand(stack, program, Some(access), register_type, depth + 1))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(AccessNode { access, register_type, children })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        stack::register_types::test_helpers::{sample_stack, sample_stack_with_imports},
+        StackProgramTypes,
+    };
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// Returns the accesses and types of the children of the given node.
+    fn children(node: &AccessNode<CurrentNetwork>) -> Vec<(Option<AccessEdge<CurrentNetwork>>, String)> {
+        node.children().iter().map(|child| (child.access().cloned(), child.register_type().to_string())).collect()
+    }
+
+    /// Returns the access to the given member.
+    fn member(name: &str) -> Option<AccessEdge<CurrentNetwork>> {
+        Some(AccessEdge::Member(Identifier::from_str(name).unwrap()))
+    }
+
+    #[test]
+    fn test_access_tree() -> Result<()> {
+        // Initialize a stack with a record over a nested struct.
+        let stack = sample_stack(
+            r"
+program access_tree.aleo;
+
+struct point:
+    x as u8;
+    y as [[u16; 2u32]; 3u32];
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+    origin as point.private;
+
+function bar:
+    input r0 as token.record;
+    output r0.amount as u64.private;",
+        )?;
+        let register_types = stack.get_register_types(&Identifier::from_str("bar")?)?;
+        let tree = register_types.access_tree(&stack, &Register::Locator(0))?;
+
+        // Ensure the root is the record, with the owner and entries as children.
+        assert_eq!(tree.access(), None);
+        assert_eq!(tree.register_type(), &RegisterType::from_str("token.record")?);
+        assert_eq!(children(&tree), vec![
+            (member("owner"), "address".to_string()),
+            (member("amount"), "u64".to_string()),
+            (member("origin"), "point".to_string()),
+        ]);
+
+        // Ensure the struct is expanded into its members.
+        let origin = &tree.children()[2];
+        assert_eq!(children(origin), vec![
+            (member("x"), "u8".to_string()),
+            (member("y"), "[[u16; 2u32]; 3u32]".to_string())
+        ]);
+        // Ensure each array is expanded into a single node for all of its elements.
+        let array = &origin.children()[1];
+        assert_eq!(children(array), vec![(Some(AccessEdge::Element), "[u16; 2u32]".to_string())]);
+        assert_eq!(children(&array.children()[0]), vec![(Some(AccessEdge::Element), "u16".to_string())]);
+
+        // Ensure the leaves have no children.
+        assert!(tree.children()[0].children().is_empty());
+        assert!(array.children()[0].children()[0].children().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_access_tree_external_record() -> Result<()> {
+        // Initialize a mock external program, with a record over a struct that is only defined in that program.
+        let mock = r"
+program mock.aleo;
+
+struct point:
+    x as u8;
+    y as u8;
+
+record token:
+    owner as address.private;
+    origin as point.private;
+
+function compute:
+    input r0 as u8.private;
+    output r0 as u8.private;";
+
+        // Initialize a stack with a function that takes the external record.
+        let stack = sample_stack_with_imports(
+            &[mock],
+            r"
+import mock.aleo;
+
+program caller.aleo;
+
+function bar:
+    input r0 as mock.aleo/token.record;
+    input r1 as u8.private;
+    output r1 as u8.private;",
+        )?;
+        let register_types = stack.get_register_types(&Identifier::from_str("bar")?)?;
+        let tree = register_types.access_tree(&stack, &Register::Locator(0))?;
+
+        // Ensure the struct of the external record is resolved in the external program.
+        assert_eq!(children(&tree), vec![
+            (member("owner"), "address".to_string()),
+            (member("origin"), "point".to_string())
+        ]);
+        assert_eq!(children(&tree.children()[1]), vec![
+            (member("x"), "u8".to_string()),
+            (member("y"), "u8".to_string())
+        ]);
+        Ok(())
+    }
+}
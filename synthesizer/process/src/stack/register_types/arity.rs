@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Ensures the number of operands in the given instruction matches the arity of its opcode.
+    /// Variadic instructions (i.e. `call`, `async`, and casts into a struct, array, or record)
+    /// are only checked against their bounds, as their exact arity depends on the program.
+    pub fn validate_arity(instruction: &Instruction<N>) -> Result<()> {
+        // Retrieve the number of operands.
+        let num_operands = instruction.operands().len();
+
+        // Determine the expected number of operands.
+        let expected = match instruction {
+            Instruction::Abs(..)
+            | Instruction::AbsWrapped(..)
+            | Instruction::Double(..)
+            | Instruction::Inv(..)
+            | Instruction::Neg(..)
+            | Instruction::Not(..)
+            | Instruction::Square(..)
+            | Instruction::SquareRoot(..) => 1,
+            Instruction::HashBHP256(..)
+            | Instruction::HashBHP512(..)
+            | Instruction::HashBHP768(..)
+            | Instruction::HashBHP1024(..)
+            | Instruction::HashKeccak256(..)
+            | Instruction::HashKeccak384(..)
+            | Instruction::HashKeccak512(..)
+            | Instruction::HashPED64(..)
+            | Instruction::HashPED128(..)
+            | Instruction::HashPSD2(..)
+            | Instruction::HashPSD4(..)
+            | Instruction::HashPSD8(..)
+            | Instruction::HashSha3_256(..)
+            | Instruction::HashSha3_384(..)
+            | Instruction::HashSha3_512(..) => 1,
+            Instruction::Ternary(..) | Instruction::SignVerify(..) => 3,
+            Instruction::Async(..) => {
+                // Ensure the number of operands is within the bounds.
+                ensure!(
+                    num_operands <= N::MAX_INPUTS,
+                    "Instruction '{}' expects at most {} operands, found {num_operands} operands",
+                    instruction.opcode(),
+                    N::MAX_INPUTS
+                );
+                return Ok(());
+            }
+            Instruction::Call(..) => {
+                // Ensure the number of operands is within the bounds.
+                ensure!(
+                    num_operands <= N::MAX_OPERANDS,
+                    "Instruction '{}' expects at most {} operands, found {num_operands} operands",
+                    instruction.opcode(),
+                    N::MAX_OPERANDS
+                );
+                return Ok(());
+            }
+            Instruction::Cast(cast) => return Self::validate_cast_arity(instruction, cast.cast_type(), num_operands),
+            Instruction::CastLossy(cast) => {
+                return Self::validate_cast_arity(instruction, cast.cast_type(), num_operands);
+            }
+            // The remaining instructions are binary.
+            _ => 2,
+        };
+
+        // Ensure the number of operands matches the expected arity.
+        ensure!(
+            num_operands == expected,
+            "Instruction '{}' expects {expected} operands, found {num_operands} operands",
+            instruction.opcode()
+        );
+        Ok(())
+    }
+
+    /// Ensures the number of operands in a cast matches its cast type.
+    fn validate_cast_arity(instruction: &Instruction<N>, cast_type: &CastType<N>, num_operands: usize) -> Result<()> {
+        match cast_type {
+            CastType::GroupXCoordinate
+            | CastType::GroupYCoordinate
+            | CastType::Plaintext(PlaintextType::Literal(..)) => ensure!(
+                num_operands == 1,
+                "Instruction '{}' expects 1 operands, found {num_operands} operands",
+                instruction.opcode()
+            ),
+            CastType::Plaintext(PlaintextType::Struct(..))
+            | CastType::Plaintext(PlaintextType::Array(..))
+            | CastType::Record(..)
+            | CastType::ExternalRecord(..) => ensure!(
+                (1..=N::MAX_OPERANDS).contains(&num_operands),
+                "Instruction '{}' expects between 1 and {} operands, found {num_operands} operands",
+                instruction.opcode(),
+                N::MAX_OPERANDS
+            ),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_validate_arity() -> Result<()> {
+        // Ensure instructions with the expected number of operands are accepted.
+        for instruction in [
+            "abs r0 into r1;",
+            "add r0 r1 into r2;",
+            "ternary r0 r1 r2 into r3;",
+            "hash.psd2 r0 into r1 as field;",
+            "hash_many.psd2 r0 r1 into r2 as field;",
+            "sign.verify r0 r1 r2 into r3;",
+            "cast r0 into r1 as u8;",
+            "cast r0 r1 r2 into r3 as token.record;",
+            "call foo r0 r1 r2 into r3;",
+        ] {
+            let instruction = Instruction::<CurrentNetwork>::from_str(instruction)?;
+            RegisterTypes::validate_arity(&instruction)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_arity_fails_on_too_many_operands() -> Result<()> {
+        // Casting to a literal only takes a single operand.
+        let instruction = Instruction::<CurrentNetwork>::from_str("cast r0 r1 into r2 as u8;")?;
+        let error = RegisterTypes::validate_arity(&instruction).unwrap_err();
+        assert_eq!(error.to_string(), "Instruction 'cast' expects 1 operands, found 2 operands");
+
+        // Casting to a group coordinate only takes a single operand.
+        let instruction = Instruction::<CurrentNetwork>::from_str("cast r0 r1 r2 into r3 as group.x;")?;
+        assert!(RegisterTypes::validate_arity(&instruction).is_err());
+        Ok(())
+    }
+}
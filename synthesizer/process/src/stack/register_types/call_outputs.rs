@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns the declared output value types of the external function referenced by the given call operator.
+    /// This method errors if the operator references a resource in the current program,
+    /// or if the external program is not imported or does not contain the function.
+    pub fn external_call_outputs(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        operator: &CallOperator<N>,
+    ) -> Result<Vec<ValueType<N>>> {
+        // Retrieve the locator of the external function.
+        let locator = match operator {
+            CallOperator::Locator(locator) => locator,
+            CallOperator::Resource(resource) => bail!("'{resource}' does not reference an external function"),
+        };
+        // Retrieve the program ID.
+        let program_id = locator.program_id();
+
+        // Ensure the locator does not reference the current program.
+        ensure!(stack.program_id() != program_id, "Locator '{locator}' does not reference an external program");
+        // Ensure the current program contains an import for this external program.
+        ensure!(
+            stack.program().imports().contains_key(program_id),
+            "External program '{program_id}' is not imported by '{}'",
+            stack.program_id()
+        );
+
+        // Retrieve the external function.
+        let function = stack.get_external_program(program_id)?.get_function_ref(locator.resource())?;
+        // Return the output value types.
+        Ok(function.outputs().iter().map(|output| output.value_type().clone()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stack::register_types::test_helpers::sample_stack_with_imports, StackProgramTypes};
+
+    #[test]
+    fn test_external_call_outputs() -> Result<()> {
+        // Initialize a mock external program.
+        let mock = r"
+program mock.aleo;
+
+function compute:
+    input r0 as u64.private;
+    input r1 as field.public;
+    add r0 r0 into r2;
+    output r2 as u64.public;
+    output r1 as field.private;";
+
+        // Initialize a stack with a function that calls the external function.
+        let stack = sample_stack_with_imports(
+            &[mock],
+            r"
+import mock.aleo;
+
+program caller.aleo;
+
+function main:
+    input r0 as u64.private;
+    call mock.aleo/compute r0 1field into r1 r2;
+    output r1 as u64.public;",
+        )?;
+        let register_types = stack.get_register_types(&Identifier::from_str("main")?)?;
+
+        // Ensure the output types of the external function are returned.
+        let operator = CallOperator::from_str("mock.aleo/compute")?;
+        assert_eq!(register_types.external_call_outputs(&stack, &operator)?, vec![
+            ValueType::from_str("u64.public")?,
+            ValueType::from_str("field.private")?
+        ]);
+
+        // Ensure missing functions, unimported programs, and local resources are rejected.
+        let operator = CallOperator::from_str("mock.aleo/missing")?;
+        assert!(register_types.external_call_outputs(&stack, &operator).is_err());
+        let operator = CallOperator::from_str("credits.aleo/transfer_public")?;
+        assert!(register_types.external_call_outputs(&stack, &operator).is_err());
+        let operator = CallOperator::from_str("main")?;
+        assert!(register_types.external_call_outputs(&stack, &operator).is_err());
+        Ok(())
+    }
+}
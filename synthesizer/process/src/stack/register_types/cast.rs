@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use synthesizer_program::Cast;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns `true` if a `cast` instruction may cast a single operand of the `source` type into the `target` type.
+    /// Literals may be cast between one another, except for signatures and strings. A struct, array, or record
+    /// is composed from its operands, so the `source` type must match its only member, element, or the owner.
+    /// This method errors if the `target` type references an undefined struct or record.
+    pub fn cast_is_valid(
+        &self,
+        stack: &impl StackProgram<N>,
+        source: &RegisterType<N>,
+        target: &RegisterType<N>,
+    ) -> Result<bool> {
+        // Returns `true` if the given literal type may be cast.
+        let is_castable =
+            |literal_type: &LiteralType| !matches!(literal_type, LiteralType::Signature | LiteralType::String);
+
+        let cast_type = match target {
+            RegisterType::Plaintext(PlaintextType::Literal(target_type)) => {
+                return Ok(match source {
+                    RegisterType::Plaintext(PlaintextType::Literal(source_type)) => {
+                        is_castable(source_type) && is_castable(target_type)
+                    }
+                    _ => false,
+                });
+            }
+            RegisterType::Plaintext(plaintext_type) => {
+                // Ensure the struct types in the target are defined.
+                Self::ensure_struct_is_defined(stack, plaintext_type)?;
+                CastType::Plaintext(plaintext_type.clone())
+            }
+            RegisterType::Record(record_name) => {
+                // Ensure the record type is defined.
+                ensure!(stack.program().contains_record(record_name), "Record '{record_name}' is not defined");
+                CastType::Record(*record_name)
+            }
+            // Casting into an external record or a future is not supported.
+            RegisterType::ExternalRecord(..) | RegisterType::Future(..) => return Ok(false),
+        };
+
+        // Check the composite cast against the rules of the `cast` instruction.
+        let cast = Cast::<N>::from_str(&format!("cast r0 into r1 as {cast_type}"))?;
+        Ok(cast.output_types(stack, &[source.clone()]).is_ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stack::register_types::test_helpers::sample_stack_with_placeholder, StackProgramTypes};
+
+    #[test]
+    fn test_cast_is_valid() -> Result<()> {
+        // Initialize a stack with a single-member struct, and two records.
+        let stack = sample_stack_with_placeholder(
+            r"
+program cast_types.aleo;
+
+struct wrapper:
+    amount as u64;
+
+record token:
+    owner as address.private;
+
+record ticket:
+    owner as address.private;",
+        )?;
+        let register_types = stack.get_register_types(&Identifier::from_str("bar")?)?;
+        let cast_is_valid = |source: &str, target: &str| {
+            register_types.cast_is_valid(&stack, &RegisterType::from_str(source)?, &RegisterType::from_str(target)?)
+        };
+
+        // Ensure literals may be cast between one another.
+        assert!(cast_is_valid("u8", "field")?);
+        assert!(cast_is_valid("field", "u8")?);
+        assert!(cast_is_valid("group", "address")?);
+        assert!(!cast_is_valid("signature", "field")?);
+        assert!(!cast_is_valid("wrapper", "u64")?);
+
+        // Ensure a plaintext may be cast into a struct with a matching member.
+        assert!(cast_is_valid("u64", "wrapper")?);
+        assert!(!cast_is_valid("u32", "wrapper")?);
+        assert!(cast_is_valid("u64", "[u64; 1u32]")?);
+
+        // Ensure a record may not be cast into another record, while its owner may.
+        assert!(!cast_is_valid("token.record", "ticket.record")?);
+        assert!(cast_is_valid("address", "ticket.record")?);
+
+        // Ensure an undefined target type is rejected.
+        assert!(cast_is_valid("u64", "missing").is_err());
+        assert!(cast_is_valid("address", "missing.record").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::Literal;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns the destination locators whose values are a deterministic function of only literal operands.
+    /// A destination is constant if every operand of its instruction is either a literal,
+    /// or a register that was itself determined to be constant.
+    /// This is intended for constant-folding passes.
+    pub fn constant_registers(&self, instructions: &[Instruction<N>]) -> IndexSet<u64> {
+        // Initialize the set of constant registers.
+        let mut constants = IndexSet::new();
+        for instruction in instructions {
+            // Skip `call` and `async` instructions, as their outputs depend on logic outside of this scope.
+            if matches!(instruction.opcode(), Opcode::Call | Opcode::Async) {
+                continue;
+            }
+            // Determine whether every operand of the instruction is constant.
+            let is_constant = !instruction.operands().is_empty()
+                && instruction.operands().iter().all(|operand| match operand {
+                    Operand::Literal(..) => true,
+                    Operand::Register(register) => constants.contains(&register.locator()),
+                    Operand::ProgramID(..)
+                    | Operand::Signer
+                    | Operand::Caller
+                    | Operand::BlockHeight
+                    | Operand::NetworkID => false,
+                });
+            // If the instruction is constant, then its destinations are constant as well.
+            if is_constant {
+                for destination in instruction.destinations() {
+                    // Retrieve the destination locator.
+                    let locator = destination.locator();
+                    // Ensure the destination is a known destination register.
+                    if self.destinations.contains_key(&locator) {
+                        constants.insert(locator);
+                    }
+                }
+            }
+        }
+        constants
+    }
+
+    /// Returns `true` if the given operand is a compile-time constant, i.e. a literal or a program ID.
+    /// Registers and the operands that are resolved from the execution context are not constant.
+    pub fn operand_is_constant(&self, operand: &Operand<N>) -> bool {
+        match operand {
+            Operand::Literal(..) | Operand::ProgramID(..) => true,
+            Operand::Register(..) | Operand::Signer | Operand::Caller | Operand::BlockHeight | Operand::NetworkID => {
+                false
+            }
+        }
+    }
+
+    /// Returns every literal operand in the given instructions, with its type, in order of appearance.
+    /// Repeated literals are returned once per use, so that a constant pool can deduplicate them.
+    pub fn literal_operands(instructions: &[Instruction<N>]) -> Vec<(Literal<N>, LiteralType)> {
+        instructions
+            .iter()
+            .flat_map(|instruction| instruction.operands())
+            .filter_map(|operand| match operand {
+                Operand::Literal(literal) => Some((literal.clone(), literal.to_type())),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::register_types::test_helpers::{sample_closure, sample_stack_with_placeholder};
+
+    #[test]
+    fn test_constant_registers() -> Result<()> {
+        // Initialize a stack with a closure that mixes literal-only and input-dependent instructions.
+        let stack = sample_stack_with_placeholder(
+            r"
+program constants.aleo;
+
+closure foo:
+    input r0 as u8;
+    add 1u8 2u8 into r1;
+    mul r1 3u8 into r2;
+    add r0 r1 into r3;
+    is.eq r2 r3 into r4;
+    ternary true r2 r1 into r5;
+    output r4 as boolean;
+    output r5 as u8;",
+        )?;
+
+        // Retrieve the closure and its register types.
+        let (closure, register_types) = sample_closure(&stack, "foo")?;
+
+        // Ensure only the literal-derived registers are constant.
+        let constants = register_types.constant_registers(closure.instructions());
+        assert_eq!(constants, IndexSet::from([1, 2, 5]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_operand_is_constant() -> Result<()> {
+        let register_types = RegisterTypes::<console::network::MainnetV0>::default();
+
+        // Ensure literals and program IDs are constant.
+        assert!(register_types.operand_is_constant(&Operand::from_str("1u8")?));
+        assert!(register_types.operand_is_constant(&Operand::from_str("credits.aleo")?));
+
+        // Ensure registers and the execution context are not constant.
+        for operand in ["r0", "r1.owner", "self.signer", "self.caller", "block.height", "network.id"] {
+            assert!(!register_types.operand_is_constant(&Operand::from_str(operand)?));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_literal_operands() -> Result<()> {
+        // Initialize a stack with a closure that uses two distinct literals.
+        let stack = sample_stack_with_placeholder(
+            r"
+program literals.aleo;
+
+closure foo:
+    input r0 as u8;
+    input r1 as field;
+    add r0 1u8 into r2;
+    mul r1 2field into r3;
+    output r2 as u8;
+    output r3 as field;",
+        )?;
+
+        // Ensure both literals are returned with their types.
+        let closure = stack.program().get_closure(&Identifier::from_str("foo")?)?;
+        let literals = RegisterTypes::<console::network::MainnetV0>::literal_operands(closure.instructions());
+        assert_eq!(literals, vec![
+            (Literal::from_str("1u8")?, LiteralType::U8),
+            (Literal::from_str("2field")?, LiteralType::Field)
+        ]);
+
+        // Ensure a function without instructions has no literals.
+        let function = stack.program().get_function(&Identifier::from_str("bar")?)?;
+        assert!(RegisterTypes::<console::network::MainnetV0>::literal_operands(function.instructions()).is_empty());
+        Ok(())
+    }
+}
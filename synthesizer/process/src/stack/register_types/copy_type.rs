@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The maximum size in bytes of a plaintext type that is considered cheap to copy.
+const MAX_COPY_SIZE_IN_BYTES: usize = 64;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns `true` if the type of the given register is cheap to copy.
+    /// This holds for plaintext types whose packed size is at most `MAX_COPY_SIZE_IN_BYTES`,
+    /// while records and futures always require a deep clone.
+    pub fn is_copy_type(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        register: &Register<N>,
+    ) -> Result<bool> {
+        match self.get_type(stack, register)? {
+            RegisterType::Plaintext(plaintext_type) => Ok(plaintext_type
+                .size_in_bytes(&|struct_name| stack.program().get_struct(struct_name))?
+                <= MAX_COPY_SIZE_IN_BYTES),
+            RegisterType::Record(..) | RegisterType::ExternalRecord(..) | RegisterType::Future(..) => Ok(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stack::register_types::test_helpers::sample_stack, StackProgramTypes};
+
+    #[test]
+    fn test_is_copy_type() -> Result<()> {
+        // Initialize a stack with registers of varying types.
+        let stack = sample_stack(
+            r"
+program copy_type.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function foo:
+    input r0 as token.record;
+    input r1 as u64.private;
+    input r2 as [field; 2u32].private;
+    input r3 as [field; 4u32].private;
+    add r0.amount r1 into r4;
+    cast r0.owner r4 into r5 as token.record;
+    output r5 as token.record;",
+        )?;
+        let register_types = stack.get_register_types(&Identifier::from_str("foo")?)?;
+        let is_copy_type = |register: &str| register_types.is_copy_type(&stack, &Register::from_str(register)?);
+
+        // Ensure literals and small arrays are copy types.
+        assert!(is_copy_type("r1")?);
+        assert!(is_copy_type("r4")?);
+        assert!(is_copy_type("r0.amount")?);
+        assert!(is_copy_type("r2")?);
+        // Ensure records and large arrays are not copy types.
+        assert!(!is_copy_type("r0")?);
+        assert!(!is_copy_type("r5")?);
+        assert!(!is_copy_type("r3")?);
+        // Ensure a missing register is an error.
+        assert!(is_copy_type("r6").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,272 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::ProgramID;
+
+use std::collections::VecDeque;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns the external programs referenced by the external record types of the input and destination registers.
+    /// This is intended for cross-program cycle detection in the call graph.
+    pub fn external_program_dependencies(&self) -> IndexSet<ProgramID<N>> {
+        self.inputs
+            .values()
+            .chain(self.destinations.values())
+            .filter_map(|register_type| match register_type {
+                RegisterType::ExternalRecord(locator) => Some(*locator.program_id()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the local closures invoked by `call` instructions in the given instructions, in order of first use.
+    /// These are the dependency edges of a closure within its program, for use in cycle detection.
+    pub fn internal_call_targets(instructions: &[Instruction<N>]) -> IndexSet<Identifier<N>> {
+        instructions
+            .iter()
+            .filter_map(|instruction| match instruction {
+                Instruction::Call(call) => match call.operator() {
+                    CallOperator::Resource(resource) => Some(*resource),
+                    CallOperator::Locator(..) => None,
+                },
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the register dataflow edges of the given instructions, as `(source, destination)` locator pairs.
+    /// An edge is emitted for every register operand of an instruction to each of its destinations,
+    /// in instruction order and without duplicates. Literals and other non-register operands contribute no edges.
+    pub fn dependency_edges(&self, instructions: &[Instruction<N>]) -> Vec<(u64, u64)> {
+        let mut edges = IndexSet::new();
+        for instruction in instructions {
+            for destination in instruction.destinations() {
+                for operand in instruction.operands() {
+                    if let Operand::Register(register) = operand {
+                        edges.insert((register.locator(), destination.locator()));
+                    }
+                }
+            }
+        }
+        edges.into_iter().collect()
+    }
+
+    /// Returns the register locators in a dependency-respecting order, where each register follows every register
+    /// it depends on, per the dataflow edges of the given instructions. Ties are broken by declaration order,
+    /// with the input registers first. This errors if the dependencies are cyclic, as in malformed instructions.
+    pub fn topological_order(&self, instructions: &[Instruction<N>]) -> Result<Vec<u64>> {
+        let edges = self.dependency_edges(instructions);
+
+        // Initialize the in-degree of every register, in declaration order, followed by any undeclared registers.
+        let mut in_degrees = self
+            .inputs
+            .keys()
+            .chain(self.destinations.keys())
+            .chain(edges.iter().flat_map(|(source, destination)| [source, destination]))
+            .map(|locator| (*locator, 0usize))
+            .collect::<IndexMap<_, _>>();
+        // Initialize the successors of every register.
+        let mut successors = IndexMap::<u64, Vec<u64>>::new();
+        for (source, destination) in &edges {
+            successors.entry(*source).or_default().push(*destination);
+            *in_degrees.entry(*destination).or_default() += 1;
+        }
+
+        // Visit the registers without pending dependencies, releasing their successors as they are visited.
+        let mut ready =
+            in_degrees.iter().filter(|(_, degree)| **degree == 0).map(|(locator, _)| *locator).collect::<VecDeque<_>>();
+        let mut order = Vec::with_capacity(in_degrees.len());
+        while let Some(locator) = ready.pop_front() {
+            order.push(locator);
+            for successor in successors.get(&locator).into_iter().flatten() {
+                let degree = in_degrees.entry(*successor).or_default();
+                *degree -= 1;
+                if *degree == 0 {
+                    ready.push_back(*successor);
+                }
+            }
+        }
+
+        // Ensure every register was visited, as the remaining registers form a cycle.
+        if order.len() != in_degrees.len() {
+            let cyclic =
+                in_degrees.keys().filter(|locator| !order.contains(locator)).map(|locator| format!("r{locator}"));
+            bail!("Found a dependency cycle among registers {}", cyclic.collect::<Vec<_>>().join(", "))
+        }
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        stack::register_types::test_helpers::{sample_stack_with_imports, sample_stack_with_placeholder},
+        StackProgramTypes,
+    };
+
+    #[test]
+    fn test_external_program_dependencies() -> Result<()> {
+        // Initialize an external program with a record.
+        let token = r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as u64.private;
+    cast self.caller r0 into r1 as token.record;
+    output r1 as token.record;";
+
+        // Initialize a stack with a closure that references records from two external programs.
+        let stack = sample_stack_with_imports(
+            &[token],
+            r"
+import credits.aleo;
+import token.aleo;
+
+program dependencies.aleo;
+
+closure foo:
+    input r0 as credits.aleo/credits.record;
+    input r1 as token.aleo/token.record;
+    add r0.microcredits r1.amount into r2;
+    output r2 as u64;
+
+closure baz:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function bar:
+    input r0 as u8.private;
+    output r0 as u8.private;",
+        )?;
+
+        // Ensure both external programs are returned.
+        let register_types = stack.get_register_types(&Identifier::from_str("foo")?)?;
+        let dependencies = register_types.external_program_dependencies();
+        assert_eq!(
+            dependencies,
+            IndexSet::from([ProgramID::from_str("credits.aleo")?, ProgramID::from_str("token.aleo")?])
+        );
+
+        // Ensure a closure without external records has no dependencies.
+        let register_types = stack.get_register_types(&Identifier::from_str("baz")?)?;
+        assert!(register_types.external_program_dependencies().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_internal_call_targets() -> Result<()> {
+        // Initialize a stack with a closure that calls two internal closures.
+        let stack = sample_stack_with_placeholder(
+            r"
+program calls.aleo;
+
+closure double:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+closure square:
+    input r0 as u64;
+    mul r0 r0 into r1;
+    output r1 as u64;
+
+closure foo:
+    input r0 as u64;
+    call square r0 into r1;
+    call double r1 into r2;
+    call square r2 into r3;
+    output r3 as u64;",
+        )?;
+
+        // Ensure both internal closures are returned, once each.
+        let closure = stack.program().get_closure(&Identifier::from_str("foo")?)?;
+        let targets = RegisterTypes::<console::network::MainnetV0>::internal_call_targets(closure.instructions());
+        assert_eq!(targets, IndexSet::from([Identifier::from_str("square")?, Identifier::from_str("double")?]));
+
+        // Ensure a closure without calls has no targets.
+        let closure = stack.program().get_closure(&Identifier::from_str("double")?)?;
+        assert!(RegisterTypes::<console::network::MainnetV0>::internal_call_targets(closure.instructions()).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_dependency_edges() -> Result<()> {
+        // Initialize a stack with a closure that chains three instructions.
+        let stack = sample_stack_with_placeholder(
+            r"
+program edges.aleo;
+
+closure foo:
+    input r0 as u64;
+    input r1 as u64;
+    add r0 r1 into r2;
+    mul r2 r2 into r3;
+    add r3 1u64 into r4;
+    output r4 as u64;",
+        )?;
+
+        // Ensure the edges follow the register dataflow, without duplicates or literal operands.
+        let closure = stack.program().get_closure(&Identifier::from_str("foo")?)?;
+        let register_types = stack.get_register_types(closure.name())?;
+        let edges = register_types.dependency_edges(closure.instructions());
+        assert_eq!(edges, vec![(0, 2), (1, 2), (2, 3), (3, 4)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_topological_order() -> Result<()> {
+        // Initialize a stack with a closure whose registers are declared in dependency order.
+        let stack = sample_stack_with_placeholder(
+            r"
+program order.aleo;
+
+closure foo:
+    input r0 as u64;
+    input r1 as u64;
+    mul r1 r1 into r2;
+    add r0 r2 into r3;
+    add r3 1u64 into r4;
+    output r4 as u64;",
+        )?;
+
+        // Ensure every register follows its dependencies.
+        let closure = stack.program().get_closure(&Identifier::from_str("foo")?)?;
+        let register_types = stack.get_register_types(closure.name())?;
+        assert_eq!(register_types.topological_order(closure.instructions())?, vec![0, 1, 2, 3, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_topological_order_cycle() -> Result<()> {
+        // Initialize register types and instructions in which two registers depend on each other.
+        let u64_type = RegisterType::<console::network::MainnetV0>::from_str("u64")?;
+        let register_types = RegisterTypes::from_parts(
+            IndexMap::from([(0, u64_type.clone())]),
+            IndexMap::from([(1, u64_type.clone()), (2, u64_type)]),
+        );
+        let instructions = [Instruction::from_str("add r0 r2 into r1;")?, Instruction::from_str("add r1 r0 into r2;")?];
+
+        // Ensure the cycle is reported.
+        let error = register_types.topological_order(&instructions).unwrap_err().to_string();
+        assert!(error.contains("r1, r2"), "{error}");
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::types::Field;
+
+/// The estimated number of constraints per input bit of a BHP or Pedersen hash or commitment.
+const BHP_CONSTRAINTS_PER_BIT: u64 = 2;
+/// The estimated number of constraints per input bit of a Poseidon hash.
+const POSEIDON_CONSTRAINTS_PER_BIT: u64 = 1;
+/// The estimated number of constraints per input bit of a Keccak or SHA-3 hash.
+const KECCAK_CONSTRAINTS_PER_BIT: u64 = 150;
+/// The estimated number of constraints of a `sign.verify` instruction.
+const SIGN_VERIFY_CONSTRAINTS: u64 = 5_000;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns an advisory estimate of the number of constraints to synthesize the given instructions.
+    ///
+    /// Each instruction is estimated from its opcode and the size in bits of its first operand.
+    /// For checked integer addition and multiplication over private operands, the estimate matches the circuit
+    /// metrics, and the test below holds it to within 10% of synthesis. All other estimates are coarse,
+    /// and only meant to guide optimization.
+    /// The constraints of `call` and `async` instructions are attributed to the callee, and are not included.
+    pub fn estimate_constraints(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        instructions: &[Instruction<N>],
+    ) -> Result<u64> {
+        instructions.iter().try_fold(0u64, |total, instruction| {
+            total
+                .checked_add(self.estimate_instruction_constraints(stack, instruction)?)
+                .ok_or_else(|| anyhow!("Overflowed while estimating the number of constraints"))
+        })
+    }
+
+    /// Returns an advisory estimate of the number of constraints to synthesize the given instruction.
+    fn estimate_instruction_constraints(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        instruction: &Instruction<N>,
+    ) -> Result<u64> {
+        // Retrieve the type of the first operand.
+        let operand_type =
+            instruction.operands().first().map(|operand| self.get_type_from_operand(stack, operand)).transpose()?;
+        // Compute the size in bits of the first operand, if it is a plaintext.
+        let bits = u64::try_from(match &operand_type {
+            Some(RegisterType::Plaintext(plaintext_type)) => {
+                plaintext_type.size_in_bits(&|struct_name| stack.program().get_struct(struct_name))?
+            }
+            _ => 0,
+        })?;
+        // Retrieve the literal type of the first operand, if it is a literal.
+        let literal_type = match operand_type {
+            Some(RegisterType::Plaintext(PlaintextType::Literal(literal_type))) => Some(literal_type),
+            _ => None,
+        };
+        // Determine whether the first operand is natively a field element, for which linear operations are free.
+        let is_native = matches!(literal_type, Some(LiteralType::Field | LiteralType::Group | LiteralType::Scalar));
+        // Determine whether the first operand is a signed integer, which requires overflow checks on the sign.
+        let is_signed = matches!(
+            literal_type,
+            Some(LiteralType::I8 | LiteralType::I16 | LiteralType::I32 | LiteralType::I64 | LiteralType::I128)
+        );
+
+        Ok(match instruction.opcode() {
+            Opcode::Literal(opcode) => match (opcode, is_native, is_signed) {
+                // Linear operations over field elements are free, and multiplicative ones take a constraint.
+                ("add" | "add.w" | "sub" | "sub.w" | "neg" | "double", true, _) => 0,
+                ("mul" | "mul.w" | "div" | "div.w" | "inv" | "square" | "sqrt", true, _) => 1,
+                // Checked signed addition, subtraction, and multiplication also constrain the sign of the result.
+                ("add" | "sub", _, true) => bits + 6,
+                ("mul", _, true) => 8 * bits + 12,
+                // The remaining integer addition, subtraction, and multiplication constrain each bit of the result.
+                ("add" | "add.w" | "sub" | "sub.w" | "mul" | "mul.w" | "abs" | "abs.w" | "neg", ..) => bits + 1,
+                // Integer division and exponentiation decompose both operands.
+                ("div" | "div.w" | "rem" | "rem.w" | "mod" | "pow" | "pow.w", ..) => 8 * bits + 12,
+                // Comparisons and shifts decompose the operands into bits.
+                ("gt" | "gte" | "lt" | "lte" | "shl" | "shl.w" | "shr" | "shr.w", ..) => bits + 5,
+                // The remaining operations are bitwise, and take a constraint per bit.
+                _ => bits,
+            },
+            // Equality takes two constraints per field element.
+            Opcode::Assert(..) | Opcode::Is(..) => 2 * bits.div_ceil(Field::<N>::size_in_data_bits() as u64).max(1),
+            // Casts decompose the operands into bits.
+            Opcode::Cast(..) => bits,
+            Opcode::Commit(..) => BHP_CONSTRAINTS_PER_BIT * bits,
+            Opcode::Hash(opcode) if opcode.contains(".psd") => POSEIDON_CONSTRAINTS_PER_BIT * bits,
+            Opcode::Hash(opcode) if opcode.contains(".keccak") || opcode.contains(".sha3") => {
+                KECCAK_CONSTRAINTS_PER_BIT * bits
+            }
+            Opcode::Hash(..) => BHP_CONSTRAINTS_PER_BIT * bits,
+            Opcode::Sign => SIGN_VERIFY_CONSTRAINTS,
+            Opcode::Call | Opcode::Async | Opcode::Command(..) => 0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stack::register_types::test_helpers::sample_stack_with_placeholder, StackProgramTypes};
+    use circuit::{Circuit, Environment, Inject, Mode, I64, U64};
+
+    /// The tolerance of the estimate, as a fraction of the synthesized number of constraints.
+    const TOLERANCE: f64 = 0.1;
+
+    #[test]
+    fn test_estimate_constraints() -> Result<()> {
+        // Initialize a stack with closures over unsigned and signed integer arithmetic.
+        let stack = sample_stack_with_placeholder(
+            r"
+program estimate.aleo;
+
+closure unsigned:
+    input r0 as u64;
+    input r1 as u64;
+    add r0 r1 into r2;
+    mul r2 r0 into r3;
+    output r3 as u64;
+
+closure signed:
+    input r0 as i64;
+    input r1 as i64;
+    add r0 r1 into r2;
+    mul r2 r0 into r3;
+    output r3 as i64;",
+        )?;
+        // Returns the estimate for the given closure.
+        let estimate = |name: &str| -> Result<u64> {
+            let closure = stack.program().get_closure(&Identifier::from_str(name)?)?;
+            stack.get_register_types(closure.name())?.estimate_constraints(&stack, closure.instructions())
+        };
+        // Returns `true` if the estimate is within the tolerance of the synthesized number of constraints.
+        let is_within_tolerance =
+            |estimate: u64, actual: u64| (estimate as f64 - actual as f64).abs() <= TOLERANCE * actual as f64;
+
+        // Synthesize the unsigned operations over private inputs, excluding the constraints of the inputs.
+        Circuit::reset();
+        let r0 = U64::<Circuit>::new(Mode::Private, console::types::U64::new(3));
+        let r1 = U64::<Circuit>::new(Mode::Private, console::types::U64::new(5));
+        let num_input_constraints = Circuit::num_constraints();
+        let _r3 = &(&r0 + &r1) * &r0;
+        let actual = Circuit::num_constraints() - num_input_constraints;
+        let estimate_unsigned = estimate("unsigned")?;
+        assert!(is_within_tolerance(estimate_unsigned, actual), "{estimate_unsigned} vs. {actual}");
+
+        // Synthesize the signed operations over private inputs, excluding the constraints of the inputs.
+        Circuit::reset();
+        let r0 = I64::<Circuit>::new(Mode::Private, console::types::I64::new(3));
+        let r1 = I64::<Circuit>::new(Mode::Private, console::types::I64::new(5));
+        let num_input_constraints = Circuit::num_constraints();
+        let _r3 = &(&r0 + &r1) * &r0;
+        let actual = Circuit::num_constraints() - num_input_constraints;
+        let estimate_signed = estimate("signed")?;
+        assert!(is_within_tolerance(estimate_signed, actual), "{estimate_signed} vs. {actual}");
+        Circuit::reset();
+
+        // Ensure signed arithmetic is estimated to be more expensive.
+        assert!(estimate_signed > estimate_unsigned);
+        Ok(())
+    }
+}
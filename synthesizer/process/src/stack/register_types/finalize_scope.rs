@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Initializes a new instance of `RegisterTypes` for the given function, along with the register types of its finalize scope.
+    /// Checks that the given function and its finalize block are well-formed for the given stack.
+    /// The finalize scope is kept separate from the function scope, and is queried with `get_finalize_type`.
+    pub fn from_function_with_finalize(
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        function: &Function<N>,
+    ) -> Result<Self> {
+        // Compute the register types of the function scope.
+        let mut register_types = Self::initialize_function_types(stack, function)?;
+        // Compute the register types of the finalize scope, if the function has a finalize block.
+        register_types.finalize_types =
+            function.finalize_logic().map(|finalize| FinalizeTypes::from_finalize(stack, finalize)).transpose()?;
+        Ok(register_types)
+    }
+
+    /// Returns the register types of the finalize scope, if they were initialized.
+    pub const fn finalize_types(&self) -> Option<&FinalizeTypes<N>> {
+        self.finalize_types.as_ref()
+    }
+
+    /// Returns the finalize type of the given register, in the finalize scope.
+    pub fn get_finalize_type(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        register: &Register<N>,
+    ) -> Result<FinalizeType<N>> {
+        match &self.finalize_types {
+            Some(finalize_types) => finalize_types.get_type(stack, register),
+            None => bail!("The register types do not contain a finalize scope"),
+        }
+    }
+
+    /// Returns the register types partitioned by scope, as `(main_registers, finalize_registers)`.
+    /// Each scope maps its register locators to their types, with the input registers first.
+    /// The finalize registers are empty if the finalize scope was not initialized.
+    pub fn scoped_types(&self) -> (IndexMap<u64, RegisterType<N>>, IndexMap<u64, RegisterType<N>>) {
+        // Collect the registers of the main scope.
+        let main = self
+            .inputs
+            .iter()
+            .chain(self.destinations.iter())
+            .map(|(locator, register_type)| (*locator, register_type.clone()));
+        // Collect the registers of the finalize scope, whose finalize types are a subset of the register types.
+        let finalize = self.finalize_types.iter().flat_map(FinalizeTypes::registers).map(|(locator, finalize_type)| {
+            let register_type = match finalize_type {
+                FinalizeType::Plaintext(plaintext_type) => RegisterType::Plaintext(plaintext_type.clone()),
+                FinalizeType::Future(locator) => RegisterType::Future(*locator),
+            };
+            (*locator, register_type)
+        });
+        (main.collect(), finalize.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::register_types::test_helpers::sample_stack;
+
+    #[test]
+    fn test_from_function_with_finalize() -> Result<()> {
+        // Initialize a stack with a function that has a finalize block.
+        let stack = sample_stack(
+            r"
+program finalize_scope.aleo;
+
+function foo:
+    input r0 as field.private;
+    async foo r0 into r1;
+    output r1 as finalize_scope.aleo/foo.future;
+
+finalize foo:
+    input r0 as field.public;
+    is.eq r0 r0 into r1;
+    add r0 r0 into r2;
+
+function bar:
+    input r0 as field.private;
+    output r0 as field.private;",
+        )?;
+        let register = |register: &str| Register::<console::network::MainnetV0>::from_str(register);
+
+        // Compute the register types of `foo`, including its finalize scope.
+        let function = stack.program().get_function(&Identifier::from_str("foo")?)?;
+        let register_types = RegisterTypes::from_function_with_finalize(&stack, &function)?;
+        assert!(register_types.finalize_types().is_some());
+
+        // Ensure the function scope types are queryable.
+        assert_eq!(register_types.get_type(&stack, &register("r0")?)?, RegisterType::from_str("field")?);
+        assert_eq!(
+            register_types.get_type(&stack, &register("r1")?)?,
+            RegisterType::from_str("finalize_scope.aleo/foo.future")?
+        );
+        // Ensure the finalize scope types are queryable.
+        assert_eq!(
+            register_types.get_finalize_type(&stack, &register("r0")?)?,
+            FinalizeType::from_str("field.public")?
+        );
+        assert_eq!(
+            register_types.get_finalize_type(&stack, &register("r1")?)?,
+            FinalizeType::from_str("boolean.public")?
+        );
+        assert_eq!(
+            register_types.get_finalize_type(&stack, &register("r2")?)?,
+            FinalizeType::from_str("field.public")?
+        );
+        // Ensure the scopes are isolated.
+        assert!(register_types.get_type(&stack, &register("r2")?).is_err());
+        assert!(!register_types.contains(&register("r2")?));
+
+        // Ensure a function without a finalize block has no finalize scope.
+        let function = stack.program().get_function(&Identifier::from_str("bar")?)?;
+        let register_types = RegisterTypes::from_function_with_finalize(&stack, &function)?;
+        assert!(register_types.finalize_types().is_none());
+        assert!(register_types.get_finalize_type(&stack, &register("r0")?).is_err());
+        // Ensure `from_function` does not include the finalize scope.
+        let function = stack.program().get_function(&Identifier::from_str("foo")?)?;
+        assert!(RegisterTypes::from_function(&stack, &function)?.finalize_types().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_scoped_types() -> Result<()> {
+        // Initialize a stack with a function that has a finalize block.
+        let stack = sample_stack(
+            r"
+program scoped.aleo;
+
+function foo:
+    input r0 as field.private;
+    input r1 as u8.public;
+    async foo r0 into r2;
+    output r2 as scoped.aleo/foo.future;
+
+finalize foo:
+    input r0 as field.public;
+    is.eq r0 r0 into r1;
+
+function bar:
+    input r0 as field.private;
+    output r0 as field.private;",
+        )?;
+        let register_type = |register_type: &str| RegisterType::<console::network::MainnetV0>::from_str(register_type);
+
+        // Ensure the registers are partitioned by scope.
+        let function = stack.program().get_function(&Identifier::from_str("foo")?)?;
+        let register_types = RegisterTypes::from_function_with_finalize(&stack, &function)?;
+        let (main, finalize) = register_types.scoped_types();
+        assert_eq!(
+            main,
+            IndexMap::from([
+                (0, register_type("field")?),
+                (1, register_type("u8")?),
+                (2, register_type("scoped.aleo/foo.future")?)
+            ])
+        );
+        assert_eq!(finalize, IndexMap::from([(0, register_type("field")?), (1, register_type("boolean")?)]));
+
+        // Ensure a function without a finalize block has an empty finalize scope.
+        let function = stack.program().get_function(&Identifier::from_str("bar")?)?;
+        let (main, finalize) = RegisterTypes::from_function_with_finalize(&stack, &function)?.scoped_types();
+        assert_eq!(main, IndexMap::from([(0, register_type("field")?)]));
+        assert!(finalize.is_empty());
+        Ok(())
+    }
+}
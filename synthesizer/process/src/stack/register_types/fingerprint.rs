@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns a 32-byte fingerprint of the input and destination registers, for use as a caching key.
+    /// The registers are encoded in order of their locators, so register types that are equal
+    /// produce the same fingerprint, regardless of the order in which their registers were inserted.
+    /// Note: The finalize types are not included in the fingerprint.
+    pub fn fingerprint(&self) -> Result<[u8; 32]> {
+        // Writes the given registers, sorted by their locators.
+        let write_registers = |registers: &IndexMap<u64, RegisterType<N>>, bytes: &mut Vec<u8>| -> Result<()> {
+            let mut registers = registers.iter().collect::<Vec<_>>();
+            registers.sort_unstable_by_key(|(locator, _)| **locator);
+            u32::try_from(registers.len())?.write_le(&mut *bytes)?;
+            for (locator, register_type) in registers {
+                locator.write_le(&mut *bytes)?;
+                register_type.write_le(&mut *bytes)?;
+            }
+            Ok(())
+        };
+
+        let mut bytes = Vec::new();
+        write_registers(&self.inputs, &mut bytes)?;
+        write_registers(&self.destinations, &mut bytes)?;
+
+        // Hash the encoding into the fingerprint.
+        let digest = utilities::bytes_from_bits_le(&N::hash_sha3_256(&bytes.to_bits_le())?);
+        digest.try_into().map_err(|_| anyhow!("The fingerprint must be 32 bytes"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_fingerprint() -> Result<()> {
+        let u8_type = RegisterType::<CurrentNetwork>::from_str("u8")?;
+        let field_type = RegisterType::<CurrentNetwork>::from_str("field")?;
+
+        // Initialize two register types with equal registers, inserted in a different order.
+        let first = RegisterTypes::from_parts(
+            IndexMap::from([(0, u8_type.clone()), (1, u8_type.clone())]),
+            IndexMap::from([(2, u8_type.clone()), (3, field_type.clone())]),
+        );
+        let second = RegisterTypes::from_parts(
+            IndexMap::from([(1, u8_type.clone()), (0, u8_type.clone())]),
+            IndexMap::from([(3, field_type.clone()), (2, u8_type.clone())]),
+        );
+
+        // Ensure the fingerprints are stable.
+        assert!(first == second);
+        assert_eq!(first.fingerprint()?, first.fingerprint()?);
+        assert_eq!(first.fingerprint()?, second.fingerprint()?);
+
+        // Ensure a changed register type changes the fingerprint.
+        let third = RegisterTypes::from_parts(
+            IndexMap::from([(0, u8_type.clone()), (1, field_type.clone())]),
+            IndexMap::from([(2, u8_type), (3, field_type)]),
+        );
+        assert_ne!(first.fingerprint()?, third.fingerprint()?);
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns a tally of the register types across all input and destination registers.
+    /// Literals are counted per literal type (e.g. `u8`, `field`), while all other kinds are
+    /// counted as `struct`, `array`, `record`, `external_record`, or `future`.
+    pub fn type_histogram(&self) -> IndexMap<String, usize> {
+        let mut histogram = IndexMap::new();
+        for register_type in self.inputs.values().chain(self.destinations.values()) {
+            // Determine the kind of the register type.
+            let kind = match register_type {
+                RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => literal_type.to_string(),
+                RegisterType::Plaintext(PlaintextType::Struct(..)) => "struct".to_string(),
+                RegisterType::Plaintext(PlaintextType::Array(..)) => "array".to_string(),
+                RegisterType::Record(..) => "record".to_string(),
+                RegisterType::ExternalRecord(..) => "external_record".to_string(),
+                RegisterType::Future(..) => "future".to_string(),
+            };
+            // Increment the count for the kind.
+            *histogram.entry(kind).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// Returns a tally of the opcodes used by the given instructions, in order of first use.
+    pub fn opcode_histogram(instructions: &[Instruction<N>]) -> IndexMap<Opcode, usize> {
+        let mut histogram = IndexMap::new();
+        for instruction in instructions {
+            // Increment the count for the opcode.
+            *histogram.entry(instruction.opcode()).or_insert(0) += 1;
+        }
+        histogram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        stack::register_types::test_helpers::{sample_stack, sample_stack_with_placeholder},
+        StackProgramTypes,
+    };
+
+    #[test]
+    fn test_type_histogram() -> Result<()> {
+        // Initialize a stack with a function that uses a mix of register types.
+        let stack = sample_stack(
+            r"
+program histogram.aleo;
+
+struct foo:
+    a as u8;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function bar:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    input r2 as field.public;
+    input r3 as foo.private;
+    input r4 as [u8; 2u32].private;
+    input r5 as token.record;
+    add r0 r1 into r6;
+    cast r5.owner r5.amount into r7 as token.record;
+    output r6 as u8.private;
+    output r7 as token.record;",
+        )?;
+
+        // Retrieve the register types of the function.
+        let register_types = stack.get_register_types(&Identifier::from_str("bar")?)?;
+
+        // Ensure the histogram tallies each kind of register type.
+        let histogram = register_types.type_histogram();
+        assert_eq!(histogram.len(), 5);
+        assert_eq!(histogram.get("u8"), Some(&3));
+        assert_eq!(histogram.get("field"), Some(&1));
+        assert_eq!(histogram.get("struct"), Some(&1));
+        assert_eq!(histogram.get("array"), Some(&1));
+        assert_eq!(histogram.get("record"), Some(&2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_opcode_histogram() -> Result<()> {
+        // Initialize a stack with a closure that uses a known mix of instructions.
+        let stack = sample_stack_with_placeholder(
+            r"
+program opcodes.aleo;
+
+closure foo:
+    input r0 as u64;
+    input r1 as u64;
+    add r0 r1 into r2;
+    mul r2 r0 into r3;
+    add r3 r1 into r4;
+    is.eq r4 r0 into r5;
+    hash.bhp256 r4 into r6 as field;
+    add r4 r4 into r7;
+    output r7 as u64;",
+        )?;
+
+        // Retrieve the instructions of the closure.
+        let closure = stack.program().get_closure(&Identifier::from_str("foo")?)?;
+
+        // Ensure the histogram tallies each opcode, in order of first use.
+        let histogram = RegisterTypes::<console::network::MainnetV0>::opcode_histogram(closure.instructions());
+        assert_eq!(histogram.into_iter().collect::<Vec<_>>(), vec![
+            (Opcode::Literal("add"), 3),
+            (Opcode::Literal("mul"), 1),
+            (Opcode::Is("is.eq"), 1),
+            (Opcode::Hash("hash.bhp256"), 1),
+        ]);
+
+        // Ensure no instructions yield an empty histogram.
+        assert!(RegisterTypes::<console::network::MainnetV0>::opcode_histogram(&[]).is_empty());
+        Ok(())
+    }
+}
@@ -23,7 +23,7 @@ impl<N: Network> RegisterTypes<N> {
         closure: &Closure<N>,
     ) -> Result<Self> {
         // Initialize a map of registers to their types.
-        let mut register_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
+        let mut register_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new(), finalize_types: None };
 
         // Step 1. Check the inputs are well-formed.
         for input in closure.inputs() {
@@ -64,7 +64,7 @@ impl<N: Network> RegisterTypes<N> {
         function: &Function<N>,
     ) -> Result<Self> {
         // Initialize a map of registers to their types.
-        let mut register_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new() };
+        let mut register_types = Self { inputs: IndexMap::new(), destinations: IndexMap::new(), finalize_types: None };
 
         /* Step 1. Check the inputs are well-formed. */
 
@@ -362,16 +362,8 @@ impl<N: Network> RegisterTypes<N> {
         // Ensure the opcode is well-formed.
         self.check_instruction_opcode(stack, closure_or_function_name, instruction)?;
 
-        // Initialize a vector to store the register types of the operands.
-        let mut operand_types = Vec::with_capacity(instruction.operands().len());
-        // Iterate over the operands, and retrieve the register type of each operand.
-        for operand in instruction.operands() {
-            // Retrieve and append the register type.
-            operand_types.push(self.get_type_from_operand(stack, operand)?);
-        }
-
         // Compute the destination register types.
-        let destination_types = instruction.output_types(stack, &operand_types)?;
+        let destination_types = self.instruction_output_types(stack, instruction)?;
 
         // Insert the destination register.
         for (destination, destination_type) in
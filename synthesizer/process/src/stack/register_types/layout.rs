@@ -0,0 +1,260 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns a stack-slot layout for the registers, mapping each register locator to its `(offset, size)` in bytes.
+    /// The registers are packed sequentially, with the input registers first, followed by the destination registers.
+    /// Each register is sized by the packed size of its type, and records include their owner and nonce.
+    pub fn stack_layout(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+    ) -> Result<IndexMap<u64, (usize, usize)>> {
+        let mut offset = 0usize;
+        self.inputs
+            .iter()
+            .chain(self.destinations.iter())
+            .map(|(locator, register_type)| {
+                // Compute the size of the register.
+                let size = match register_type {
+                    RegisterType::Plaintext(plaintext_type) => {
+                        plaintext_type.size_in_bytes(&|struct_name| stack.program().get_struct(struct_name))?
+                    }
+                    RegisterType::Record(record_name) => {
+                        record_size_in_bytes(stack.program(), stack.program().get_record(record_name)?)?
+                    }
+                    RegisterType::ExternalRecord(locator) => {
+                        let external = stack.get_external_program(locator.program_id())?;
+                        record_size_in_bytes(external, external.get_record(locator.resource())?)?
+                    }
+                    RegisterType::Future(..) => bail!("Register 'r{locator}' is a future, which has no stack layout"),
+                };
+                // Assign the next slot to the register.
+                let slot = (offset, size);
+                offset =
+                    offset.checked_add(size).ok_or_else(|| anyhow!("Overflowed while computing the stack layout"))?;
+                Ok((*locator, slot))
+            })
+            .collect()
+    }
+
+    /// Returns the bit layout of the registers, mapping each register locator to the `[start, end)` range
+    /// of boolean variables its value occupies in a flattened witness.
+    /// The registers are laid out sequentially in declaration order, with the input registers first.
+    pub fn bit_layout(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+    ) -> Result<IndexMap<u64, core::ops::Range<usize>>> {
+        let mut start = 0usize;
+        self.inputs
+            .iter()
+            .chain(self.destinations.iter())
+            .map(|(locator, register_type)| {
+                // Compute the number of bits of the register.
+                let size = match register_type {
+                    RegisterType::Plaintext(plaintext_type) => {
+                        plaintext_type.size_in_bits(&|struct_name| stack.program().get_struct(struct_name))?
+                    }
+                    RegisterType::Record(record_name) => {
+                        record_size_in_bits(stack.program(), stack.program().get_record(record_name)?)?
+                    }
+                    RegisterType::ExternalRecord(locator) => {
+                        let external = stack.get_external_program(locator.program_id())?;
+                        record_size_in_bits(external, external.get_record(locator.resource())?)?
+                    }
+                    RegisterType::Future(..) => bail!("Register 'r{locator}' is a future, which has no bit layout"),
+                };
+                // Assign the next range to the register.
+                let end =
+                    start.checked_add(size).ok_or_else(|| anyhow!("Overflowed while computing the bit layout"))?;
+                let range = start..end;
+                start = end;
+                Ok((*locator, range))
+            })
+            .collect()
+    }
+
+    /// Returns the number of registers whose size in bits exceeds `max_bits`, per the bit layout of the registers.
+    pub fn count_registers_exceeding(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        max_bits: usize,
+    ) -> Result<usize> {
+        Ok(self.bit_layout(stack)?.values().filter(|range| range.len() > max_bits).count())
+    }
+
+    /// Returns the `[start, end)` bit range of each member within the flat encoding of the given struct register,
+    /// in declaration order. This errors if the register is not a struct.
+    pub fn struct_field_offsets(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        register: &Register<N>,
+    ) -> Result<IndexMap<Identifier<N>, core::ops::Range<usize>>> {
+        // Retrieve the struct type of the register.
+        let struct_name = match self.get_type(stack, register)? {
+            RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => struct_name,
+            register_type => bail!("Register '{register}' is a '{register_type}', not a struct"),
+        };
+        // Assign each member the next range of bits.
+        let mut start = 0usize;
+        stack
+            .program()
+            .get_struct(&struct_name)?
+            .members()
+            .iter()
+            .map(|(member_name, member_type)| {
+                let size = member_type.size_in_bits(&|struct_name| stack.program().get_struct(struct_name))?;
+                let end = start
+                    .checked_add(size)
+                    .ok_or_else(|| anyhow!("Overflowed while computing the offsets of struct '{struct_name}'"))?;
+                let range = start..end;
+                start = end;
+                Ok((*member_name, range))
+            })
+            .collect()
+    }
+}
+
+/// Returns the packed size in bytes of the given record type, including its owner and nonce.
+fn record_size_in_bytes<N: Network>(program: &Program<N>, record_type: &RecordType<N>) -> Result<usize> {
+    // Initialize the size with the owner and the nonce.
+    let size = LiteralType::Address.size_in_bytes::<N>() as usize + LiteralType::Group.size_in_bytes::<N>() as usize;
+    // Add the size of each entry.
+    record_type.entries().values().try_fold(size, |size, entry_type| {
+        size.checked_add(entry_type.plaintext_type().size_in_bytes(&|struct_name| program.get_struct(struct_name))?)
+            .ok_or_else(|| anyhow!("Overflowed while computing the size of record '{}'", record_type.name()))
+    })
+}
+
+/// Returns the size in bits of the given record type, including its owner and nonce.
+pub(super) fn record_size_in_bits<N: Network>(program: &Program<N>, record_type: &RecordType<N>) -> Result<usize> {
+    // Initialize the size with the owner and the nonce.
+    let size = LiteralType::Address.size_in_bits::<N>() as usize + LiteralType::Group.size_in_bits::<N>() as usize;
+    // Add the size of each entry.
+    record_type.entries().values().try_fold(size, |size, entry_type| {
+        size.checked_add(entry_type.plaintext_type().size_in_bits(&|struct_name| program.get_struct(struct_name))?)
+            .ok_or_else(|| anyhow!("Overflowed while computing the size of record '{}'", record_type.name()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stack::register_types::test_helpers::sample_stack_with_placeholder, StackProgramTypes};
+
+    #[test]
+    fn test_stack_layout() -> Result<()> {
+        // Initialize a stack with a closure whose registers differ in size.
+        let stack = sample_stack_with_placeholder(
+            r"
+program layout.aleo;
+
+struct pair:
+    a as u16;
+    b as [u8; 3u32];
+
+closure foo:
+    input r0 as u8;
+    input r1 as field;
+    add r1 r1 into r2;
+    cast 1u8 2u8 3u8 into r3 as [u8; 3u32];
+    cast 1u16 r3 into r4 as pair;
+    output r2 as field;",
+        )?;
+        let register_types = stack.get_register_types(&Identifier::from_str("foo")?)?;
+
+        // Ensure the registers are packed sequentially.
+        let layout = register_types.stack_layout(&stack)?;
+        assert_eq!(layout, IndexMap::from([(0, (0, 1)), (1, (1, 32)), (2, (33, 32)), (3, (65, 3)), (4, (68, 5))]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bit_layout() -> Result<()> {
+        // Initialize a stack with a closure over a `u8` and a `u32` register.
+        let stack = sample_stack_with_placeholder(
+            r"
+program bit_layout.aleo;
+
+closure foo:
+    input r0 as u8;
+    input r1 as u32;
+    cast r0 into r2 as u32;
+    add r1 r2 into r3;
+    output r3 as u32;",
+        )?;
+        let register_types = stack.get_register_types(&Identifier::from_str("foo")?)?;
+
+        // Ensure the registers occupy consecutive bit ranges, in declaration order.
+        let layout = register_types.bit_layout(&stack)?;
+        assert_eq!(layout, IndexMap::from([(0, 0..8), (1, 8..40), (2, 40..72), (3, 72..104)]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_registers_exceeding() -> Result<()> {
+        // Initialize a stack with a closure over one oversized array register.
+        let stack = sample_stack_with_placeholder(
+            r"
+program large_types.aleo;
+
+closure foo:
+    input r0 as [field; 4u32];
+    input r1 as field;
+    add r1 r1 into r2;
+    output r2 as field;",
+        )?;
+        let register_types = stack.get_register_types(&Identifier::from_str("foo")?)?;
+
+        // Ensure only the array register exceeds a threshold above the size of a field.
+        assert_eq!(register_types.count_registers_exceeding(&stack, 512)?, 1);
+        // Ensure every register exceeds a threshold below the size of a field.
+        assert_eq!(register_types.count_registers_exceeding(&stack, 8)?, 3);
+        // Ensure no register exceeds a threshold at the size of the array.
+        let max_bits = 4 * LiteralType::Field.size_in_bits::<console::network::MainnetV0>() as usize;
+        assert_eq!(register_types.count_registers_exceeding(&stack, max_bits)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_field_offsets() -> Result<()> {
+        // Initialize a stack with a closure over a two-member struct register.
+        let stack = sample_stack_with_placeholder(
+            r"
+program offsets.aleo;
+
+struct pair:
+    a as u16;
+    b as [u8; 3u32];
+
+closure foo:
+    input r0 as pair;
+    input r1 as u8;
+    output r1 as u8;",
+        )?;
+        let register_types = stack.get_register_types(&Identifier::from_str("foo")?)?;
+
+        // Ensure the members occupy consecutive bit ranges, in declaration order.
+        let offsets = register_types.struct_field_offsets(&stack, &Register::Locator(0))?;
+        assert_eq!(
+            offsets,
+            IndexMap::from([(Identifier::from_str("a")?, 0..16), (Identifier::from_str("b")?, 16..40)])
+        );
+
+        // Ensure a register that is not a struct is rejected.
+        assert!(register_types.struct_field_offsets(&stack, &Register::Locator(1)).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,224 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{layout::record_size_in_bits, *};
+use console::types::Field;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Ensures the register types are within the structural limits of the network, bailing on the first violation.
+    /// This checks, in order:
+    ///   - the number of input registers is at most `N::MAX_INPUTS`,
+    ///   - the number of destination registers is at most `N::MAX_INSTRUCTIONS`,
+    ///   - the number of dimensions of each array type is at most `N::MAX_DATA_DEPTH`,
+    ///   - the nesting depth of each register type (i.e. its deepest access) is at most `N::MAX_DATA_DEPTH`,
+    ///   - the size in bits of each register is at most `N::MAX_DATA_SIZE_IN_FIELDS` fields of data.
+    pub fn validate_limits(&self, stack: &(impl StackMatches<N> + StackProgram<N>)) -> Result<()> {
+        // Ensure the number of registers is within bounds.
+        ensure!(
+            self.inputs.len() <= N::MAX_INPUTS,
+            "Register types exceed the maximum number of inputs ({} > {})",
+            self.inputs.len(),
+            N::MAX_INPUTS
+        );
+        ensure!(
+            self.destinations.len() <= N::MAX_INSTRUCTIONS,
+            "Register types exceed the maximum number of destinations ({} > {})",
+            self.destinations.len(),
+            N::MAX_INSTRUCTIONS
+        );
+
+        // Compute the maximum size in bits of a register.
+        let max_size_in_bits = N::MAX_DATA_SIZE_IN_FIELDS as usize * Field::<N>::size_in_data_bits();
+
+        for (locator, register_type) in self.inputs.iter().chain(self.destinations.iter()) {
+            // Retrieve the program and the record type, if the register is a record.
+            let (program, record) = match register_type {
+                RegisterType::Plaintext(..) => (stack.program(), None),
+                RegisterType::Record(record_name) => (stack.program(), Some(stack.program().get_record(record_name)?)),
+                RegisterType::ExternalRecord(locator) => {
+                    let external = stack.get_external_program(locator.program_id())?;
+                    (external, Some(external.get_record(locator.resource())?))
+                }
+                // Futures are bounded by the limits of the function they refer to.
+                RegisterType::Future(..) => continue,
+            };
+            // Collect the plaintext types of the register.
+            let plaintext_types = match (register_type, record) {
+                (RegisterType::Plaintext(plaintext_type), _) => vec![plaintext_type],
+                (_, Some(record)) => record.entries().values().map(|entry_type| entry_type.plaintext_type()).collect(),
+                _ => vec![],
+            };
+
+            // Ensure the array dimensions are within bounds.
+            for plaintext_type in &plaintext_types {
+                let num_dimensions = max_array_dimensions(program, plaintext_type)?;
+                ensure!(
+                    num_dimensions <= N::MAX_DATA_DEPTH,
+                    "Register 'r{locator}' exceeds the maximum number of array dimensions ({num_dimensions} > {})",
+                    N::MAX_DATA_DEPTH
+                );
+            }
+
+            // Ensure the nesting depth is within bounds. Note that a record entry is one access below the record.
+            let depth = plaintext_types.iter().try_fold(0, |depth, plaintext_type| {
+                Ok::<_, Error>(depth.max(nesting_depth(program, plaintext_type)?))
+            })? + usize::from(record.is_some());
+            ensure!(
+                depth <= N::MAX_DATA_DEPTH,
+                "Register 'r{locator}' exceeds the maximum access depth ({depth} > {})",
+                N::MAX_DATA_DEPTH
+            );
+
+            // Ensure the size in bits is within bounds.
+            let size_in_bits = match (register_type, record) {
+                (RegisterType::Plaintext(plaintext_type), _) => {
+                    plaintext_type.size_in_bits(&|struct_name| program.get_struct(struct_name))?
+                }
+                (_, Some(record)) => record_size_in_bits(program, record)?,
+                _ => 0,
+            };
+            ensure!(
+                size_in_bits <= max_size_in_bits,
+                "Register 'r{locator}' exceeds the maximum size in bits ({size_in_bits} > {max_size_in_bits})"
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Returns the nesting depth of the given plaintext type, where a literal has a depth of zero.
+fn nesting_depth<N: Network>(program: &Program<N>, plaintext_type: &PlaintextType<N>) -> Result<usize> {
+    match plaintext_type {
+        PlaintextType::Literal(..) => Ok(0),
+        PlaintextType::Struct(struct_name) => {
+            let members = program.get_struct(struct_name)?.members();
+            let depth = members
+                .values()
+                .try_fold(0, |depth, member_type| Ok::<_, Error>(depth.max(nesting_depth(program, member_type)?)))?;
+            Ok(depth + 1)
+        }
+        PlaintextType::Array(array_type) => Ok(nesting_depth(program, array_type.next_element_type())? + 1),
+    }
+}
+
+/// Returns the largest number of dimensions of an array type within the given plaintext type.
+fn max_array_dimensions<N: Network>(program: &Program<N>, plaintext_type: &PlaintextType<N>) -> Result<usize> {
+    match plaintext_type {
+        PlaintextType::Literal(..) => Ok(0),
+        PlaintextType::Struct(struct_name) => program
+            .get_struct(struct_name)?
+            .members()
+            .values()
+            .try_fold(0, |max, member_type| Ok(max.max(max_array_dimensions(program, member_type)?))),
+        PlaintextType::Array(array_type) => {
+            // Count the dimensions of this array type.
+            let mut num_dimensions = 1;
+            let mut inner = array_type;
+            while let Some(next) = inner.inner() {
+                num_dimensions += 1;
+                inner = next;
+            }
+            // Include the arrays within the base element type.
+            Ok(num_dimensions.max(max_array_dimensions(program, inner.next_element_type())?))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::register_types::test_helpers::sample_stack_with_placeholder;
+    use console::types::U32;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// A program with no types of its own.
+    const PROGRAM: &str = "program limits.aleo;";
+
+    /// Returns the error message of validating the given register types against the given stack.
+    fn validate(stack: &crate::Stack<CurrentNetwork>, register_types: RegisterTypes<CurrentNetwork>) -> String {
+        register_types.validate_limits(stack).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_validate_limits() -> Result<()> {
+        let stack = sample_stack_with_placeholder(PROGRAM)?;
+        // Ensure register types within the limits are accepted.
+        let register_types = RegisterTypes::from_parts(
+            (0..CurrentNetwork::MAX_INPUTS as u64)
+                .map(|locator| (locator, RegisterType::from_str("u8").unwrap()))
+                .collect(),
+            IndexMap::from([(16, RegisterType::from_str("[[u8; 32u32]; 32u32]")?)]),
+        );
+        register_types.validate_limits(&stack)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_limits_inputs() -> Result<()> {
+        let stack = sample_stack_with_placeholder(PROGRAM)?;
+        let inputs =
+            (0..=CurrentNetwork::MAX_INPUTS as u64).map(|locator| (locator, RegisterType::from_str("u8").unwrap()));
+        let error = validate(&stack, RegisterTypes::from_parts(inputs.collect(), IndexMap::new()));
+        assert!(error.contains("maximum number of inputs"), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_limits_destinations() -> Result<()> {
+        let stack = sample_stack_with_placeholder(PROGRAM)?;
+        let destinations = (0..=CurrentNetwork::MAX_INSTRUCTIONS as u64)
+            .map(|locator| (locator, RegisterType::from_str("u8").unwrap()));
+        let error = validate(&stack, RegisterTypes::from_parts(IndexMap::new(), destinations.collect()));
+        assert!(error.contains("maximum number of destinations"), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_limits_array_dimensions() -> Result<()> {
+        let stack = sample_stack_with_placeholder(PROGRAM)?;
+        // Construct an array type with one more dimension than the maximum.
+        let array_type =
+            ArrayType::new(PlaintextType::from_str("u8")?, vec![U32::new(1); CurrentNetwork::MAX_DATA_DEPTH])?;
+        let array_type = ArrayType::new(PlaintextType::Array(array_type), vec![U32::new(1)])?;
+        let register_type = RegisterType::Plaintext(PlaintextType::Array(array_type));
+        let error = validate(&stack, RegisterTypes::from_parts(IndexMap::from([(0, register_type)]), IndexMap::new()));
+        assert!(error.contains("maximum number of array dimensions"), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_limits_depth() -> Result<()> {
+        // Initialize a program with a chain of structs, one level deeper than the maximum.
+        let mut program = "program limits.aleo; struct s0: a as u8;".to_string();
+        for i in 1..=CurrentNetwork::MAX_DATA_DEPTH {
+            program.push_str(&format!(" struct s{i}: a as s{};", i - 1));
+        }
+        let stack = sample_stack_with_placeholder(&program)?;
+
+        let register_type = RegisterType::from_str(&format!("s{}", CurrentNetwork::MAX_DATA_DEPTH))?;
+        let error = validate(&stack, RegisterTypes::from_parts(IndexMap::from([(0, register_type)]), IndexMap::new()));
+        assert!(error.contains("maximum access depth"), "{error}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_limits_size_in_bits() -> Result<()> {
+        let stack = sample_stack_with_placeholder(PROGRAM)?;
+        let register_type = RegisterType::from_str("[[[field; 32u32]; 32u32]; 32u32]")?;
+        let error = validate(&stack, RegisterTypes::from_parts(IndexMap::from([(0, register_type)]), IndexMap::new()));
+        assert!(error.contains("maximum size in bits"), "{error}");
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The maximum number of accesses in a register operand before it is flagged.
+const MAX_ACCESS_DEPTH: usize = 4;
+/// The maximum packed size in bytes of an array register before it is flagged.
+const MAX_ARRAY_SIZE_IN_BYTES: usize = 1024;
+
+/// A static warning raised by `RegisterTypes::lint`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lint<N: Network> {
+    /// The code of the lint (i.e. `unused-destination`).
+    code: &'static str,
+    /// The message describing the lint.
+    message: String,
+    /// The register the lint refers to, if any.
+    register: Option<Register<N>>,
+}
+
+impl<N: Network> Lint<N> {
+    /// Returns the code of the lint.
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the message of the lint.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the register the lint refers to, if any.
+    pub const fn register(&self) -> Option<&Register<N>> {
+        self.register.as_ref()
+    }
+}
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns the static warnings for the given instructions and output operands. This reports:
+    ///   - `unused-destination`: a destination register that is never read,
+    ///   - `deep-access`: a register operand with more than `MAX_ACCESS_DEPTH` accesses,
+    ///   - `large-array`: an array register with a packed size above `MAX_ARRAY_SIZE_IN_BYTES`.
+    pub fn lint(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        instructions: &[Instruction<N>],
+        outputs: &[Operand<N>],
+    ) -> Vec<Lint<N>> {
+        let mut lints = Vec::new();
+
+        // Collect the register operands that are read, by the instructions or the outputs.
+        let reads = instructions
+            .iter()
+            .flat_map(|instruction| instruction.operands())
+            .chain(outputs)
+            .filter_map(|operand| match operand {
+                Operand::Register(register) => Some(register),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // Flag the destination registers that are never read.
+        for instruction in instructions {
+            for destination in instruction.destinations() {
+                if !reads.iter().any(|register| register.locator() == destination.locator()) {
+                    lints.push(Lint {
+                        code: "unused-destination",
+                        message: format!(
+                            "Destination register '{destination}' of '{}' is never read",
+                            instruction.opcode()
+                        ),
+                        register: Some(destination),
+                    });
+                }
+            }
+        }
+
+        // Flag the register operands with excessively deep access paths.
+        for register in &reads {
+            if let Register::Access(_, accesses) = register {
+                if accesses.len() > MAX_ACCESS_DEPTH {
+                    lints.push(Lint {
+                        code: "deep-access",
+                        message: format!(
+                            "Register '{register}' has {} accesses, exceeding the limit of {MAX_ACCESS_DEPTH}",
+                            accesses.len()
+                        ),
+                        register: Some((*register).clone()),
+                    });
+                }
+            }
+        }
+
+        // Flag the registers typed as very large arrays.
+        for (locator, register_type) in self.inputs.iter().chain(self.destinations.iter()) {
+            if let RegisterType::Plaintext(plaintext_type @ PlaintextType::Array(..)) = register_type {
+                // Note: Arrays whose size cannot be computed are left to the type checker.
+                if let Some(size) = plaintext_type
+                    .size_in_bytes(&|struct_name| stack.program().get_struct(struct_name))
+                    .ok()
+                    .filter(|size| *size > MAX_ARRAY_SIZE_IN_BYTES)
+                {
+                    lints.push(Lint {
+                        code: "large-array",
+                        message: format!(
+                            "Register 'r{locator}' is an array of {size} bytes, exceeding the limit of {MAX_ARRAY_SIZE_IN_BYTES}"
+                        ),
+                        register: Some(Register::Locator(*locator)),
+                    });
+                }
+            }
+        }
+
+        lints
+    }
+
+    /// Returns the locators of the input registers that are never read, by the given instructions or outputs.
+    /// Unlike the `unused-destination` lint, this covers the inputs, in the order they are declared.
+    pub fn unused_inputs(&self, instructions: &[Instruction<N>], outputs: &[Operand<N>]) -> Vec<u64> {
+        // Collect the locators of the register operands that are read.
+        let reads = instructions
+            .iter()
+            .flat_map(|instruction| instruction.operands())
+            .chain(outputs)
+            .filter_map(|operand| match operand {
+                Operand::Register(register) => Some(register.locator()),
+                _ => None,
+            })
+            .collect::<IndexSet<_>>();
+        // Return the inputs that are not read.
+        self.inputs.keys().copied().filter(|locator| !reads.contains(locator)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::register_types::test_helpers::{sample_closure, sample_stack_with_placeholder};
+
+    #[test]
+    fn test_lint() -> Result<()> {
+        // Initialize a stack with a closure that has an unused destination and a large array input.
+        let stack = sample_stack_with_placeholder(
+            r"
+program lint.aleo;
+
+closure foo:
+    input r0 as u8;
+    input r1 as [[field; 4u32]; 32u32];
+    add r0 r0 into r2;
+    mul r0 r0 into r3;
+    output r2 as u8;",
+        )?;
+
+        // Retrieve the closure and its register types.
+        let (closure, register_types) = sample_closure(&stack, "foo")?;
+
+        // Lint the closure.
+        let outputs = closure.outputs().iter().map(|output| output.operand().clone()).collect::<Vec<_>>();
+        let lints = register_types.lint(&stack, closure.instructions(), &outputs);
+
+        // Ensure both lints are reported, for the expected registers.
+        assert_eq!(lints.len(), 2);
+        assert_eq!(lints[0].code(), "unused-destination");
+        assert_eq!(lints[0].register(), Some(&Register::Locator(3)));
+        assert_eq!(lints[1].code(), "large-array");
+        assert_eq!(lints[1].register(), Some(&Register::Locator(1)));
+        assert!(lints[1].message().contains("4096 bytes"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unused_inputs() -> Result<()> {
+        // Initialize a stack with a closure that ignores one of its inputs.
+        let stack = sample_stack_with_placeholder(
+            r"
+program unused.aleo;
+
+closure foo:
+    input r0 as u8;
+    input r1 as u8;
+    input r2 as u8;
+    add r0 r0 into r3;
+    output r3 as u8;
+    output r2 as u8;",
+        )?;
+
+        // Retrieve the closure and its register types.
+        let (closure, register_types) = sample_closure(&stack, "foo")?;
+
+        // Ensure only the ignored input is reported, as the others are read by an instruction or an output.
+        let outputs = closure.outputs().iter().map(|output| output.operand().clone()).collect::<Vec<_>>();
+        assert_eq!(register_types.unused_inputs(closure.instructions(), &outputs), vec![1]);
+        // Ensure the output-only input is reported, if the outputs are not given.
+        assert_eq!(register_types.unused_inputs(closure.instructions(), &[]), vec![1, 2]);
+        Ok(())
+    }
+}
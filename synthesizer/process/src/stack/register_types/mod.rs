@@ -12,8 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod abi;
+mod access_tree;
+mod arity;
+mod call_outputs;
+mod cast;
+mod constants;
+mod copy_type;
+mod dependencies;
+mod estimate;
+mod finalize_scope;
+mod fingerprint;
+mod histogram;
 mod initialize;
+mod layout;
+mod limits;
+mod lint;
 mod matches;
+mod operand_types;
+mod pack;
+mod parts;
+mod predict;
+mod reachable;
+mod remap;
+mod signature;
+mod simplify;
+mod substitute;
+mod typescript;
+
+pub use access_tree::{AccessEdge, AccessNode};
+pub use lint::Lint;
+
+use crate::FinalizeTypes;
 
 use console::{
     network::prelude::*,
@@ -54,6 +84,8 @@ pub struct RegisterTypes<N: Network> {
     inputs: IndexMap<u64, RegisterType<N>>,
     /// The mapping of all destination registers to their defined types.
     destinations: IndexMap<u64, RegisterType<N>>,
+    /// The register types of the finalize scope, if initialized together with the function's finalize block.
+    finalize_types: Option<FinalizeTypes<N>>,
 }
 
 impl<N: Network> RegisterTypes<N> {
@@ -263,4 +295,63 @@ impl<N: Network> RegisterTypes<N> {
             RegisterRefType::Future(locator) => RegisterType::Future(*locator),
         })
     }
+
+    /// Ensures the struct referenced by the given plaintext type, if any, is defined in the program.
+    fn ensure_struct_is_defined(stack: &impl StackProgram<N>, plaintext_type: &PlaintextType<N>) -> Result<()> {
+        let struct_name = match plaintext_type {
+            PlaintextType::Array(array_type) => match array_type.base_element_type() {
+                PlaintextType::Struct(struct_name) => Some(struct_name),
+                _ => None,
+            },
+            PlaintextType::Struct(struct_name) => Some(struct_name),
+            PlaintextType::Literal(..) => None,
+        };
+        if let Some(struct_name) = struct_name {
+            ensure!(stack.program().contains_struct(struct_name), "Struct '{struct_name}' is not defined");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use crate::{Process, Stack, StackProgramTypes};
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    /// Returns a new stack for the given program string.
+    pub fn sample_stack(program: &str) -> Result<Stack<CurrentNetwork>> {
+        sample_stack_with_imports(&[], program)
+    }
+
+    /// Returns a new stack for the given program string, after adding the given imported programs to the process.
+    pub fn sample_stack_with_imports(imports: &[&str], program: &str) -> Result<Stack<CurrentNetwork>> {
+        // Parse the program.
+        let program = Program::<CurrentNetwork>::from_str(program)?;
+        // Initialize a new process.
+        let mut process = Process::<CurrentNetwork>::load()?;
+        // Add the imported programs to the process.
+        for import in imports {
+            process.add_program(&Program::from_str(import)?)?;
+        }
+        // Return the stack.
+        Stack::new(&process, &program)
+    }
+
+    /// Returns a new stack for the given program string, after appending a placeholder function `bar`.
+    /// This allows tests to declare only the closures and types under test, as a program requires a function.
+    pub fn sample_stack_with_placeholder(program: &str) -> Result<Stack<CurrentNetwork>> {
+        sample_stack(&format!("{program}\n\nfunction bar:\n    input r0 as u8.private;\n    output r0 as u8.private;"))
+    }
+
+    /// Returns the closure with the given name from the given stack, along with its register types.
+    pub fn sample_closure<'a>(
+        stack: &'a Stack<CurrentNetwork>,
+        name: &str,
+    ) -> Result<(Closure<CurrentNetwork>, &'a RegisterTypes<CurrentNetwork>)> {
+        let closure = stack.program().get_closure(&Identifier::from_str(name)?)?;
+        let register_types = stack.get_register_types(closure.name())?;
+        Ok((closure, register_types))
+    }
 }
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns the register types of the given operands, in order.
+    /// This fails on the first operand whose type cannot be resolved, reporting the index of the operand.
+    pub fn types_from_operands(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        operands: &[Operand<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        operands
+            .iter()
+            .enumerate()
+            .map(|(index, operand)| {
+                self.get_type_from_operand(stack, operand)
+                    .map_err(|error| anyhow!("Failed to resolve the type of operand {index} ('{operand}'): {error}"))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stack::register_types::test_helpers::sample_stack, StackProgramTypes};
+
+    #[test]
+    fn test_types_from_operands() -> Result<()> {
+        // Initialize a stack with a function over a struct input.
+        let stack = sample_stack(
+            r"
+program operand_types.aleo;
+
+struct foo:
+    a as u8;
+    b as field;
+
+function bar:
+    input r0 as foo.private;
+    add r0.a 1u8 into r1;
+    output r1 as u8.private;",
+        )?;
+        let register_types = stack.get_register_types(&Identifier::from_str("bar")?)?;
+
+        // Resolve a mixed list of literal, register, and caller operands.
+        let operands = ["1u64", "r0", "r0.b", "r1", "self.caller"]
+            .into_iter()
+            .map(Operand::from_str)
+            .collect::<Result<Vec<_>>>()?;
+        let types = register_types.types_from_operands(&stack, &operands)?;
+        assert_eq!(types, vec![
+            RegisterType::from_str("u64")?,
+            RegisterType::from_str("foo")?,
+            RegisterType::from_str("field")?,
+            RegisterType::from_str("u8")?,
+            RegisterType::from_str("address")?,
+        ]);
+
+        // Ensure the first failing operand is reported by index.
+        let operands = ["1u64", "r2", "block.height"].into_iter().map(Operand::from_str).collect::<Result<Vec<_>>>()?;
+        let error = register_types.types_from_operands(&stack, &operands).unwrap_err();
+        assert!(error.to_string().contains("operand 1 ('r2')"));
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns a synthetic struct named `inputs`, whose members are the input register types in order.
+    /// Each member is named after its register, i.e. the member for `r2` is `input_2`.
+    /// This errors if an input is not a plaintext type, or refers to a struct that is not defined in the program.
+    pub fn inputs_as_struct(&self, stack: &(impl StackMatches<N> + StackProgram<N>)) -> Result<StructType<N>> {
+        // Ensure there is at least one input.
+        ensure!(!self.inputs.is_empty(), "Cannot pack zero inputs into a struct");
+        // Construct the struct members.
+        let mut members = IndexMap::with_capacity(self.inputs.len());
+        for (locator, register_type) in &self.inputs {
+            let plaintext_type = match register_type {
+                RegisterType::Plaintext(plaintext_type) => plaintext_type,
+                _ => bail!("Input register 'r{locator}' of type '{register_type}' is not a plaintext type"),
+            };
+            // Ensure the struct of the member is defined.
+            Self::ensure_struct_is_defined(stack, plaintext_type)?;
+            members.insert(Identifier::from_str(&format!("input_{locator}"))?, plaintext_type.clone());
+        }
+        StructType::new(Identifier::from_str("inputs")?, members)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stack::register_types::test_helpers::sample_stack, StackProgramTypes};
+
+    #[test]
+    fn test_inputs_as_struct() -> Result<()> {
+        // Initialize a stack with a closure over several plaintext inputs, and a function over a record.
+        let stack = sample_stack(
+            r"
+program pack.aleo;
+
+struct point:
+    x as u8;
+    y as u8;
+
+record token:
+    owner as address.private;
+
+closure foo:
+    input r0 as u8;
+    input r1 as field;
+    input r2 as point;
+    input r3 as [u16; 2u32];
+    add r0 r0 into r4;
+    output r4 as u8;
+
+function bar:
+    input r0 as token.record;
+    output r0.owner as address.private;",
+        )?;
+
+        // Ensure the struct members match the closure inputs, in order.
+        let struct_ = stack.get_register_types(&Identifier::from_str("foo")?)?.inputs_as_struct(&stack)?;
+        assert_eq!(struct_.name(), &Identifier::from_str("inputs")?);
+        let members = struct_.members().iter().map(|(name, member_type)| (name.to_string(), member_type.to_string()));
+        assert_eq!(members.collect::<Vec<_>>(), vec![
+            ("input_0".to_string(), "u8".to_string()),
+            ("input_1".to_string(), "field".to_string()),
+            ("input_2".to_string(), "point".to_string()),
+            ("input_3".to_string(), "[u16; 2u32]".to_string()),
+        ]);
+
+        // Ensure a record input cannot be packed.
+        assert!(stack.get_register_types(&Identifier::from_str("bar")?)?.inputs_as_struct(&stack).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Initializes a new instance of `RegisterTypes` from the given input and destination registers.
+    ///
+    /// Note: This method performs **no** validation of the given registers, and does not attach any finalize types.
+    /// Prefer `RegisterTypes::from_closure` or `RegisterTypes::from_function`, which check well-formedness.
+    pub fn from_parts(inputs: IndexMap<u64, RegisterType<N>>, destinations: IndexMap<u64, RegisterType<N>>) -> Self {
+        Self { inputs, destinations, finalize_types: None }
+    }
+
+    /// Returns the input and destination registers, and the finalize types if any, consuming the register types.
+    pub fn into_parts(
+        self,
+    ) -> (IndexMap<u64, RegisterType<N>>, IndexMap<u64, RegisterType<N>>, Option<FinalizeTypes<N>>) {
+        (self.inputs, self.destinations, self.finalize_types)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stack::register_types::test_helpers::sample_stack_with_placeholder, StackProgramTypes};
+
+    #[test]
+    fn test_parts_round_trip() -> Result<()> {
+        // Initialize a stack with a closure.
+        let stack = sample_stack_with_placeholder(
+            r"
+program parts.aleo;
+
+closure foo:
+    input r0 as u8;
+    input r1 as u8;
+    add r0 r1 into r2;
+    cast r2 into r3 as field;
+    output r3 as field;",
+        )?;
+        let register_types = stack.get_register_types(&Identifier::from_str("foo")?)?.clone();
+
+        // Ensure the parts are returned as expected.
+        let (inputs, destinations, finalize_types) = register_types.clone().into_parts();
+        assert_eq!(inputs.keys().copied().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(destinations.keys().copied().collect::<Vec<_>>(), vec![2, 3]);
+        // Note: A closure has no finalize types, so `from_parts` is able to restore it.
+        assert!(finalize_types.is_none());
+
+        // Ensure the round trip preserves equality.
+        assert!(RegisterTypes::from_parts(inputs, destinations) == register_types);
+        Ok(())
+    }
+}
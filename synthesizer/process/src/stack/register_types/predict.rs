@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns the register type that the destination of the given instruction would receive,
+    /// without adding the destination to the register types.
+    pub fn predict_destination_type(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        instruction: &Instruction<N>,
+    ) -> Result<RegisterType<N>> {
+        // Compute the destination register types.
+        let mut destination_types = self.instruction_output_types(stack, instruction)?;
+        // Ensure the instruction has exactly one destination.
+        match destination_types.len() {
+            1 => Ok(destination_types.remove(0)),
+            num_destinations => bail!("Instruction '{instruction}' has {num_destinations} destinations, expected 1"),
+        }
+    }
+
+    /// Returns the destination register types of the given instruction,
+    /// as determined by its opcode and the register types of its operands.
+    pub(super) fn instruction_output_types(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        instruction: &Instruction<N>,
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Initialize a vector to store the register types of the operands.
+        let mut operand_types = Vec::with_capacity(instruction.operands().len());
+        // Iterate over the operands, and retrieve the register type of each operand.
+        for operand in instruction.operands() {
+            // Retrieve and append the register type.
+            operand_types.push(self.get_type_from_operand(stack, operand)?);
+        }
+        // Compute the destination register types.
+        instruction.output_types(stack, &operand_types)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stack::register_types::test_helpers::sample_stack_with_placeholder, StackProgramTypes};
+
+    #[test]
+    fn test_predict_destination_type() -> Result<()> {
+        // Initialize a stack with a closure.
+        let stack = sample_stack_with_placeholder(
+            r"
+program predict.aleo;
+
+closure foo:
+    input r0 as u8;
+    input r1 as u8;
+    add r0 r1 into r2;
+    output r2 as u8;",
+        )?;
+
+        // Retrieve the register types of the closure.
+        let register_types = stack.get_register_types(&Identifier::from_str("foo")?)?;
+
+        // Predict the type of an `add` that has not been added to the closure.
+        let instruction = Instruction::from_str("add r0 r2 into r3;")?;
+        let predicted = register_types.predict_destination_type(&stack, &instruction)?;
+        assert_eq!(predicted, RegisterType::from_str("u8")?);
+        // Ensure the destination was not added to the register types.
+        assert!(!register_types.contains(&Register::from_str("r3")?));
+
+        // Predict the type of a comparison.
+        let instruction = Instruction::from_str("is.eq r0 r1 into r3;")?;
+        assert_eq!(register_types.predict_destination_type(&stack, &instruction)?, RegisterType::from_str("boolean")?);
+
+        // Ensure mismatched operand types are rejected.
+        let instruction = Instruction::from_str("add r0 1u16 into r3;")?;
+        assert!(register_types.predict_destination_type(&stack, &instruction).is_err());
+        // Ensure undefined operands are rejected.
+        let instruction = Instruction::from_str("add r0 r4 into r5;")?;
+        assert!(register_types.predict_destination_type(&stack, &instruction).is_err());
+        Ok(())
+    }
+}
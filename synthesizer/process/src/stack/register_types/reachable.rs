@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns the locators of the registers that influence the given output operands, or the side effects of a scope.
+    /// This performs a backward dataflow walk through the given instructions, starting from the outputs
+    /// and the operands of the `assert`, `call`, and `async` instructions.
+    /// As such, any destination register that is not in the returned set is dead code.
+    pub fn reachable_from_outputs(&self, instructions: &[Instruction<N>], outputs: &[Operand<N>]) -> IndexSet<u64> {
+        // Returns the locator of the given operand, if it is a register defined in this scope.
+        let to_locator = |operand: &Operand<N>| match operand {
+            Operand::Register(register) => Some(register.locator())
+                .filter(|locator| self.inputs.contains_key(locator) || self.destinations.contains_key(locator)),
+            _ => None,
+        };
+
+        // Initialize the reachable set with the registers of the outputs.
+        let mut reachable = outputs.iter().filter_map(to_locator).collect::<IndexSet<_>>();
+        // Walk the instructions backwards, as a register can only be read after it is assigned.
+        for instruction in instructions.iter().rev() {
+            // An instruction with side effects must be kept, regardless of whether its destinations are used.
+            let has_side_effects = matches!(instruction.opcode(), Opcode::Assert(_) | Opcode::Call | Opcode::Async);
+            // If the instruction has side effects, or any of its destinations is reachable, then so are its operands.
+            if has_side_effects
+                || instruction.destinations().iter().any(|destination| reachable.contains(&destination.locator()))
+            {
+                reachable.extend(instruction.operands().iter().filter_map(to_locator));
+            }
+        }
+        reachable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::register_types::test_helpers::{sample_closure, sample_stack_with_placeholder};
+
+    #[test]
+    fn test_reachable_from_outputs() -> Result<()> {
+        // Initialize a stack with a closure that contains a dead computation branch.
+        let stack = sample_stack_with_placeholder(
+            r"
+program reachable.aleo;
+
+closure foo:
+    input r0 as u8;
+    input r1 as u8;
+    add r0 r0 into r2;
+    mul r1 r1 into r3;
+    add r3 r1 into r4;
+    add r2 1u8 into r5;
+    output r5 as u8;",
+        )?;
+
+        // Retrieve the closure and its register types.
+        let (closure, register_types) = sample_closure(&stack, "foo")?;
+
+        // Compute the registers reachable from the outputs.
+        let outputs = closure.outputs().iter().map(|output| output.operand().clone()).collect::<Vec<_>>();
+        let reachable = register_types.reachable_from_outputs(closure.instructions(), &outputs);
+
+        // Ensure the dead branch is excluded.
+        assert_eq!(reachable, IndexSet::from([5, 2, 0]));
+        assert!(!reachable.contains(&1) && !reachable.contains(&3) && !reachable.contains(&4));
+        Ok(())
+    }
+
+    #[test]
+    fn test_reachable_from_assertions() -> Result<()> {
+        // Initialize a stack with a closure whose assertion depends on registers that do not influence the outputs.
+        let stack = sample_stack_with_placeholder(
+            r"
+program reachable.aleo;
+
+closure foo:
+    input r0 as u8;
+    input r1 as u8;
+    add r1 r1 into r2;
+    assert.eq r2 2u8;
+    mul r0 r0 into r3;
+    add r1 1u8 into r4;
+    output r3 as u8;",
+        )?;
+
+        // Retrieve the closure and its register types.
+        let (closure, register_types) = sample_closure(&stack, "foo")?;
+
+        // Compute the registers reachable from the outputs.
+        let outputs = closure.outputs().iter().map(|output| output.operand().clone()).collect::<Vec<_>>();
+        let reachable = register_types.reachable_from_outputs(closure.instructions(), &outputs);
+
+        // Ensure the registers of the assertion are retained, and only the unused computation is excluded.
+        assert_eq!(reachable, IndexSet::from([3, 0, 2, 1]));
+        assert!(!reachable.contains(&4));
+        Ok(())
+    }
+}
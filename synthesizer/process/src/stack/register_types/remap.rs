@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns a copy of the register types, with the input and destination registers renumbered per the given mapping.
+    /// Every register must be present in the mapping, and no two registers may be mapped to the same locator.
+    pub fn remap_locators(&self, mapping: &IndexMap<u64, u64>) -> Result<RegisterTypes<N>> {
+        // Initialize the set of assigned locators, to detect collisions.
+        let mut assigned = IndexSet::new();
+        // Rekeys the given registers per the mapping.
+        let mut remap = |registers: &IndexMap<u64, RegisterType<N>>| {
+            registers
+                .iter()
+                .map(|(locator, register_type)| {
+                    // Retrieve the new locator.
+                    let Some(new_locator) = mapping.get(locator) else {
+                        bail!("Register 'r{locator}' is missing from the locator mapping")
+                    };
+                    // Ensure the new locator is not already assigned.
+                    ensure!(assigned.insert(*new_locator), "Register 'r{locator}' collides on 'r{new_locator}'");
+                    Ok((*new_locator, register_type.clone()))
+                })
+                .collect::<Result<IndexMap<_, _>>>()
+        };
+        Ok(Self {
+            inputs: remap(&self.inputs)?,
+            destinations: remap(&self.destinations)?,
+            finalize_types: self.finalize_types.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stack::register_types::test_helpers::sample_stack_with_placeholder, StackProgramTypes};
+
+    #[test]
+    fn test_remap_locators() -> Result<()> {
+        // Initialize a stack with a closure over two registers.
+        let stack = sample_stack_with_placeholder(
+            r"
+program remap.aleo;
+
+closure foo:
+    input r0 as u8;
+    cast r0 into r1 as field;
+    output r1 as field;",
+        )?;
+        let register_types = stack.get_register_types(&Identifier::from_str("foo")?)?;
+
+        // Ensure the registers are rekeyed, with their types preserved.
+        let remapped = register_types.remap_locators(&IndexMap::from([(0, 7), (1, 3)]))?;
+        assert!(remapped.is_input(&Register::Locator(7)));
+        assert!(!remapped.contains(&Register::Locator(0)) && !remapped.contains(&Register::Locator(1)));
+        assert_eq!(remapped.get_type(&stack, &Register::Locator(7))?, RegisterType::from_str("u8")?);
+        assert_eq!(remapped.get_type(&stack, &Register::Locator(3))?, RegisterType::from_str("field")?);
+
+        // Ensure a missing mapping entry is rejected.
+        assert!(register_types.remap_locators(&IndexMap::from([(0, 7)])).is_err());
+        // Ensure a collision is rejected.
+        assert!(register_types.remap_locators(&IndexMap::from([(0, 7), (1, 7)])).is_err());
+        Ok(())
+    }
+}
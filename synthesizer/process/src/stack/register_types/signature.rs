@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns a canonical bit encoding of the type signature, for hashing into a signature ID.
+    /// The encoding consists of the number of inputs, the input types in order, the number of outputs,
+    /// and the given output types in order. Internal destinations, and the register locators, are not encoded.
+    pub fn to_minimal_bits(&self, output_types: &[RegisterType<N>]) -> Result<Vec<bool>> {
+        let mut bytes = Vec::new();
+        // Write the input types.
+        u16::try_from(self.inputs.len())?.write_le(&mut bytes)?;
+        self.inputs.values().try_for_each(|register_type| register_type.write_le(&mut bytes))?;
+        // Write the output types.
+        u16::try_from(output_types.len())?.write_le(&mut bytes)?;
+        output_types.iter().try_for_each(|register_type| register_type.write_le(&mut bytes))?;
+        Ok(bytes.to_bits_le())
+    }
+
+    /// Returns a printable signature for the given closure, such as `closure foo(r0: u32, r1: token) -> (field, bool)`.
+    pub fn signature_string(&self, stack: &impl StackProgram<N>, closure_name: &Identifier<N>) -> Result<String> {
+        // Retrieve the closure.
+        let closure = stack.program().get_closure(closure_name)?;
+        // Format the inputs, in order of their registers.
+        let inputs = closure
+            .inputs()
+            .iter()
+            .map(|input| {
+                let register = input.register();
+                match self.inputs.get(&register.locator()) {
+                    Some(register_type) => Ok(format!("{register}: {register_type}")),
+                    None => bail!("Input register '{register}' in closure '{closure_name}' is not typed"),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Format the outputs.
+        let outputs = closure.outputs().iter().map(|output| output.register_type().to_string()).collect::<Vec<_>>();
+        let outputs = match outputs.len() {
+            1 => outputs[0].clone(),
+            _ => format!("({})", outputs.join(", ")),
+        };
+        Ok(format!("closure {closure_name}({}) -> {outputs}", inputs.join(", ")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stack::register_types::test_helpers::sample_stack_with_placeholder, StackProgramTypes};
+
+    #[test]
+    fn test_to_minimal_bits() -> Result<()> {
+        // Initialize a stack with closures whose signatures are equal, except for one that changes an input type.
+        let stack = sample_stack_with_placeholder(
+            r"
+program signature.aleo;
+
+closure foo:
+    input r0 as u8;
+    input r1 as u8;
+    add r0 r1 into r2;
+    output r2 as u8;
+
+closure baz:
+    input r0 as u8;
+    input r1 as u8;
+    mul r0 r1 into r2;
+    add r2 r0 into r3;
+    output r3 as u8;
+
+closure qux:
+    input r0 as u8;
+    input r1 as u16;
+    add r0 r0 into r2;
+    output r2 as u8;",
+        )?;
+
+        // Returns the signature bits of the given closure.
+        let to_minimal_bits = |name: &str| -> Result<Vec<bool>> {
+            let closure = stack.program().get_closure(&Identifier::from_str(name)?)?;
+            let output_types =
+                closure.outputs().iter().map(|output| output.register_type().clone()).collect::<Vec<_>>();
+            stack.get_register_types(closure.name())?.to_minimal_bits(&output_types)
+        };
+
+        // Ensure equal signatures produce equal bits, regardless of the instructions.
+        assert_eq!(to_minimal_bits("foo")?, to_minimal_bits("baz")?);
+        // Ensure a changed input type changes the bits.
+        assert_ne!(to_minimal_bits("foo")?, to_minimal_bits("qux")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_signature_string() -> Result<()> {
+        // Initialize a stack with a closure over two inputs and two outputs.
+        let stack = sample_stack_with_placeholder(
+            r"
+program signature.aleo;
+
+struct token:
+    amount as u64;
+
+closure foo:
+    input r0 as u32;
+    input r1 as token;
+    cast r0 into r2 as field;
+    is.eq r1.amount 0u64 into r3;
+    output r2 as field;
+    output r3 as boolean;",
+        )?;
+
+        let name = Identifier::from_str("foo")?;
+        let signature = stack.get_register_types(&name)?.signature_string(&stack, &name)?;
+        assert_eq!(signature, "closure foo(r0: u32, r1: token) -> (field, boolean)");
+
+        // Ensure a missing closure is rejected.
+        assert!(stack.get_register_types(&name)?.signature_string(&stack, &Identifier::from_str("baz")?).is_err());
+        Ok(())
+    }
+}
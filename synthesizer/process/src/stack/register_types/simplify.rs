@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns the normalized form of the given register type, in which every length-1 array dimension
+    /// is collapsed into its element type, i.e. `[u8; 1u32]` becomes `u8`.
+    ///
+    /// Note: This is an advisory helper for analyzers and optimization passes. It does **not** change
+    /// the on-chain semantics of the type, and must not be used in place of the declared type.
+    pub fn simplify_type(register_type: &RegisterType<N>) -> RegisterType<N> {
+        match register_type {
+            RegisterType::Plaintext(plaintext_type) => {
+                RegisterType::Plaintext(Self::simplify_plaintext_type(plaintext_type))
+            }
+            RegisterType::Record(..) | RegisterType::ExternalRecord(..) | RegisterType::Future(..) => {
+                register_type.clone()
+            }
+        }
+    }
+
+    /// Returns the normalized form of the given plaintext type, collapsing length-1 array dimensions.
+    fn simplify_plaintext_type(plaintext_type: &PlaintextType<N>) -> PlaintextType<N> {
+        match plaintext_type {
+            PlaintextType::Literal(..) | PlaintextType::Struct(..) => plaintext_type.clone(),
+            PlaintextType::Array(array_type) => {
+                // Simplify the element type first, so that nested length-1 dimensions are collapsed.
+                let element_type = Self::simplify_plaintext_type(array_type.next_element_type());
+                match **array_type.length() == 1 {
+                    true => element_type,
+                    // Note: This unwrap is safe, as the element type has at most as many dimensions as before,
+                    // and the length was already checked when the original array type was constructed.
+                    false => PlaintextType::Array(ArrayType::new(element_type, vec![*array_type.length()]).unwrap()),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_simplify_type() -> Result<()> {
+        // Ensure a length-1 array collapses into its element type.
+        let register_type = RegisterType::<CurrentNetwork>::from_str("[u8; 1u32]")?;
+        assert_eq!(RegisterTypes::simplify_type(&register_type), RegisterType::from_str("u8")?);
+
+        // Ensure an array with more than one element is unchanged.
+        let register_type = RegisterType::<CurrentNetwork>::from_str("[u8; 2u32]")?;
+        assert_eq!(RegisterTypes::simplify_type(&register_type), register_type);
+
+        // Ensure nested length-1 dimensions are collapsed.
+        let register_type = RegisterType::<CurrentNetwork>::from_str("[[u8; 1u32]; 2u32]")?;
+        assert_eq!(RegisterTypes::simplify_type(&register_type), RegisterType::from_str("[u8; 2u32]")?);
+        let register_type = RegisterType::<CurrentNetwork>::from_str("[[u8; 2u32]; 1u32]")?;
+        assert_eq!(RegisterTypes::simplify_type(&register_type), RegisterType::from_str("[u8; 2u32]")?);
+
+        // Ensure non-array types are unchanged.
+        let register_type = RegisterType::<CurrentNetwork>::from_str("token.record")?;
+        assert_eq!(RegisterTypes::simplify_type(&register_type), register_type);
+        Ok(())
+    }
+}
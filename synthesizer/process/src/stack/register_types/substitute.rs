@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns a copy of the register types, with the type of the given input register replaced by `new_type`.
+    /// The destination types are copied as is, and are not re-derived from the new input type.
+    pub fn with_input_type(&self, locator: u64, new_type: RegisterType<N>) -> Result<RegisterTypes<N>> {
+        // Ensure the locator is an input register.
+        ensure!(self.inputs.contains_key(&locator), "Register 'r{locator}' is not an input register");
+        // Substitute the input type.
+        let mut register_types = self.clone();
+        register_types.inputs.insert(locator, new_type);
+        Ok(register_types)
+    }
+
+    /// Replaces the type of the given destination register with `new_type`, in place.
+    /// This is intended for transforms that insert `cast` instructions, which re-type the cast destinations.
+    /// Note: Input registers cannot be re-typed, as their types are fixed by the signature.
+    pub fn retype_after_cast(&mut self, locator: u64, new_type: RegisterType<N>) -> Result<()> {
+        // Ensure the locator is not an input register.
+        ensure!(!self.inputs.contains_key(&locator), "Input register 'r{locator}' cannot be re-typed");
+        // Retrieve the destination type.
+        match self.destinations.get_mut(&locator) {
+            Some(register_type) => *register_type = new_type,
+            None => bail!("Register 'r{locator}' is not a destination register"),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{stack::register_types::test_helpers::sample_stack_with_placeholder, StackProgramTypes};
+
+    #[test]
+    fn test_with_input_type() -> Result<()> {
+        // Initialize a stack with a closure.
+        let stack = sample_stack_with_placeholder(
+            r"
+program substitute.aleo;
+
+closure foo:
+    input r0 as u8;
+    cast r0 into r1 as field;
+    output r1 as field;",
+        )?;
+        let register_types = stack.get_register_types(&Identifier::from_str("foo")?)?;
+
+        // Substitute the input type.
+        let substituted = register_types.with_input_type(0, RegisterType::from_str("u16")?)?;
+        assert_eq!(substituted.get_type(&stack, &Register::Locator(0))?, RegisterType::from_str("u16")?);
+        assert_eq!(substituted.get_type(&stack, &Register::Locator(1))?, RegisterType::from_str("field")?);
+
+        // Ensure the original is unchanged.
+        assert_eq!(register_types.get_type(&stack, &Register::Locator(0))?, RegisterType::from_str("u8")?);
+
+        // Ensure a destination register cannot be substituted.
+        assert!(register_types.with_input_type(1, RegisterType::from_str("u16")?).is_err());
+        // Ensure a missing register cannot be substituted.
+        assert!(register_types.with_input_type(2, RegisterType::from_str("u16")?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_retype_after_cast() -> Result<()> {
+        // Initialize a stack with a closure.
+        let stack = sample_stack_with_placeholder(
+            r"
+program retype.aleo;
+
+closure foo:
+    input r0 as u8;
+    cast r0 into r1 as field;
+    output r1 as field;",
+        )?;
+        let mut register_types = stack.get_register_types(&Identifier::from_str("foo")?)?.clone();
+
+        // Re-type the destination register.
+        register_types.retype_after_cast(1, RegisterType::from_str("scalar")?)?;
+        assert_eq!(register_types.get_type(&stack, &Register::Locator(1))?, RegisterType::from_str("scalar")?);
+
+        // Ensure an input register cannot be re-typed.
+        assert!(register_types.retype_after_cast(0, RegisterType::from_str("u16")?).is_err());
+        assert_eq!(register_types.get_type(&stack, &Register::Locator(0))?, RegisterType::from_str("u8")?);
+        // Ensure a missing register cannot be re-typed.
+        assert!(register_types.retype_after_cast(2, RegisterType::from_str("u16")?).is_err());
+        Ok(())
+    }
+}
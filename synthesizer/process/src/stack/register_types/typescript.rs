@@ -0,0 +1,321 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::ProgramID;
+
+/// The TypeScript interfaces referenced by a declaration.
+struct Interfaces<N: Network> {
+    /// The ID of the program that declares the closure.
+    program_id: ProgramID<N>,
+    /// The interface names, keyed by the program ID and name of their struct or record.
+    names: IndexMap<(ProgramID<N>, Identifier<N>), String>,
+    /// The interface declarations, in dependency order.
+    declarations: Vec<String>,
+}
+
+impl<N: Network> Interfaces<N> {
+    /// Returns the interface name for the given struct or record, and `true` if the name was newly assigned.
+    /// Definitions from other programs are prefixed with their program name, and a name that is already taken
+    /// by another definition is suffixed with a number, so that every definition has a unique interface name.
+    fn assign_name(&mut self, program_id: &ProgramID<N>, identifier: &Identifier<N>) -> (String, bool) {
+        if let Some(name) = self.names.get(&(*program_id, *identifier)) {
+            return (name.clone(), false);
+        }
+        // Construct the name, prefixed with the program name if the definition is external.
+        let base = match program_id == &self.program_id {
+            true => to_pascal_case(&identifier.to_string()),
+            false => to_pascal_case(&format!("{}_{identifier}", program_id.name())),
+        };
+        // Suffix the name with a number, if it is already taken.
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while self.names.values().any(|taken| taken == &name) {
+            name = format!("{base}{suffix}");
+            suffix += 1;
+        }
+        self.names.insert((*program_id, *identifier), name.clone());
+        (name, true)
+    }
+}
+
+impl<N: Network> RegisterTypes<N> {
+    /// Returns TypeScript declarations for the given closure, whose register types are `self`.
+    /// This emits an interface for each struct and record type referenced by the closure inputs and outputs,
+    /// followed by a function declaration for the closure signature.
+    pub fn to_typescript(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        closure: &Closure<N>,
+    ) -> Result<String> {
+        // Ensure the register types correspond to the closure.
+        ensure!(
+            self.inputs.len() == closure.inputs().len(),
+            "The register types do not match the inputs of closure '{}'",
+            closure.name()
+        );
+
+        // Initialize the interfaces.
+        let mut interfaces =
+            Interfaces { program_id: *stack.program_id(), names: IndexMap::new(), declarations: vec![] };
+        // Declare the parameters.
+        let parameters = closure
+            .inputs()
+            .iter()
+            .map(|input| {
+                let register_type = self.get_type(stack, input.register())?;
+                Ok(format!(
+                    "{}: {}",
+                    input.register(),
+                    register_type_to_typescript(stack, &register_type, &mut interfaces)?
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Declare the return type.
+        let outputs = closure
+            .outputs()
+            .iter()
+            .map(|output| register_type_to_typescript(stack, output.register_type(), &mut interfaces))
+            .collect::<Result<Vec<_>>>()?;
+        let return_type = match outputs.len() {
+            0 => "void".to_string(),
+            1 => outputs[0].clone(),
+            _ => format!("[{}]", outputs.join(", ")),
+        };
+
+        // Construct the declarations.
+        let mut declarations =
+            interfaces.declarations.into_iter().map(|interface| format!("{interface}\n\n")).collect::<String>();
+        declarations.push_str(&format!(
+            "export declare function {}({}): {return_type};\n",
+            closure.name(),
+            parameters.join(", ")
+        ));
+        Ok(declarations)
+    }
+}
+
+/// Returns the TypeScript type for the given register type, declaring any referenced interfaces.
+fn register_type_to_typescript<N: Network>(
+    stack: &(impl StackMatches<N> + StackProgram<N>),
+    register_type: &RegisterType<N>,
+    interfaces: &mut Interfaces<N>,
+) -> Result<String> {
+    match register_type {
+        RegisterType::Plaintext(plaintext_type) => {
+            plaintext_type_to_typescript(stack.program(), plaintext_type, interfaces)
+        }
+        RegisterType::Record(record_name) => {
+            record_type_to_typescript(stack.program(), stack.program().get_record(record_name)?, interfaces)
+        }
+        RegisterType::ExternalRecord(locator) => {
+            let external = stack.get_external_program(locator.program_id())?;
+            record_type_to_typescript(external, external.get_record(locator.resource())?, interfaces)
+        }
+        RegisterType::Future(locator) => bail!("Future '{locator}' cannot be declared in TypeScript"),
+    }
+}
+
+/// Returns the TypeScript type for the given plaintext type, declaring any referenced interfaces.
+/// The structs of the type are resolved in the given program.
+fn plaintext_type_to_typescript<N: Network>(
+    program: &Program<N>,
+    plaintext_type: &PlaintextType<N>,
+    interfaces: &mut Interfaces<N>,
+) -> Result<String> {
+    match plaintext_type {
+        PlaintextType::Literal(literal_type) => Ok(literal_type_to_typescript(literal_type).to_string()),
+        PlaintextType::Struct(struct_name) => {
+            let (name, is_new) = interfaces.assign_name(program.id(), struct_name);
+            if is_new {
+                // Declare the members, which also declares the interfaces they depend on.
+                let members = program
+                    .get_struct(struct_name)?
+                    .members()
+                    .iter()
+                    .map(|(member, member_type)| {
+                        Ok(format!("  {member}: {};", plaintext_type_to_typescript(program, member_type, interfaces)?))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                interfaces.declarations.push(format!("export interface {name} {{\n{}\n}}", members.join("\n")));
+            }
+            Ok(name)
+        }
+        PlaintextType::Array(array_type) => {
+            Ok(format!("{}[]", plaintext_type_to_typescript(program, array_type.next_element_type(), interfaces)?))
+        }
+    }
+}
+
+/// Returns the TypeScript interface name for the given record type, declaring the interface if necessary.
+/// The structs of the record entries are resolved in the given program.
+fn record_type_to_typescript<N: Network>(
+    program: &Program<N>,
+    record_type: &RecordType<N>,
+    interfaces: &mut Interfaces<N>,
+) -> Result<String> {
+    let (name, is_new) = interfaces.assign_name(program.id(), record_type.name());
+    if is_new {
+        // Declare the entries, which also declares the interfaces they depend on.
+        let mut entries = vec!["  owner: string;".to_string()];
+        for (entry, entry_type) in record_type.entries() {
+            let entry_type = plaintext_type_to_typescript(program, entry_type.plaintext_type(), interfaces)?;
+            entries.push(format!("  {entry}: {entry_type};"));
+        }
+        entries.push("  _nonce: string;".to_string());
+        interfaces.declarations.push(format!("export interface {name} {{\n{}\n}}", entries.join("\n")));
+    }
+    Ok(name)
+}
+
+/// Returns the TypeScript type for the given literal type.
+/// Integers that fit in 53 bits are numbers, larger integers are bigints, and all other literals are strings.
+fn literal_type_to_typescript(literal_type: &LiteralType) -> &'static str {
+    match literal_type {
+        LiteralType::Boolean => "boolean",
+        LiteralType::I8
+        | LiteralType::I16
+        | LiteralType::I32
+        | LiteralType::U8
+        | LiteralType::U16
+        | LiteralType::U32 => "number",
+        LiteralType::I64 | LiteralType::I128 | LiteralType::U64 | LiteralType::U128 => "bigint",
+        LiteralType::Address
+        | LiteralType::Field
+        | LiteralType::Group
+        | LiteralType::Scalar
+        | LiteralType::Signature
+        | LiteralType::String => "string",
+    }
+}
+
+/// Returns the given snake-case identifier in Pascal case, i.e. `token_info` becomes `TokenInfo`.
+fn to_pascal_case(identifier: &str) -> String {
+    identifier
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack::register_types::test_helpers::{
+        sample_closure,
+        sample_stack_with_imports,
+        sample_stack_with_placeholder,
+    };
+
+    #[test]
+    fn test_to_typescript() -> Result<()> {
+        // Initialize a stack with a closure that takes a struct input.
+        let stack = sample_stack_with_placeholder(
+            r"
+program typescript.aleo;
+
+struct point:
+    x as u32;
+    y as field;
+
+closure foo:
+    input r0 as point;
+    input r1 as u64;
+    add r0.x 1u32 into r2;
+    output r2 as u32;
+    output r0 as point;",
+        )?;
+
+        // Retrieve the closure and its register types.
+        let (closure, register_types) = sample_closure(&stack, "foo")?;
+
+        // Ensure the declarations match.
+        let expected = r"export interface Point {
+  x: number;
+  y: string;
+}
+
+export declare function foo(r0: Point, r1: bigint): [number, Point];
+";
+        assert_eq!(register_types.to_typescript(&stack, &closure)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_typescript_external() -> Result<()> {
+        // Initialize a mock external program, with a record over a struct that is only defined in that program.
+        let mock = r"
+program mock.aleo;
+
+struct point:
+    x as u8;
+
+record token:
+    owner as address.private;
+    origin as point.private;
+
+function compute:
+    input r0 as u8.private;
+    output r0 as u8.private;";
+
+        // Initialize a stack with a closure over the external record, and a struct whose name collides with it.
+        let stack = sample_stack_with_imports(
+            &[mock],
+            r"
+import mock.aleo;
+
+program caller.aleo;
+
+struct mock_point:
+    y as u8;
+
+closure foo:
+    input r0 as mock.aleo/token.record;
+    input r1 as mock_point;
+    output r1 as mock_point;
+
+function bar:
+    input r0 as u8.private;
+    output r0 as u8.private;",
+        )?;
+
+        // Retrieve the closure and its register types.
+        let (closure, register_types) = sample_closure(&stack, "foo")?;
+
+        // Ensure the external struct is resolved in its program, and the colliding name is disambiguated.
+        let expected = r"export interface MockPoint {
+  x: number;
+}
+
+export interface MockToken {
+  owner: string;
+  origin: MockPoint;
+  _nonce: string;
+}
+
+export interface MockPoint2 {
+  y: number;
+}
+
+export declare function foo(r0: MockToken, r1: MockPoint2): MockPoint2;
+";
+        assert_eq!(register_types.to_typescript(&stack, &closure)?, expected);
+        Ok(())
+    }
+}
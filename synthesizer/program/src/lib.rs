@@ -418,6 +418,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
     /// This method will halt if the record name is already in use in the program.
     /// This method will halt if the record name is a reserved opcode or keyword.
     /// This method will halt if any records in the record's members are not already defined.
+    /// This method will halt if the record exceeds the maximum number of record entries.
     #[inline]
     fn add_record(&mut self, record: RecordType<N>) -> Result<()> {
         // Retrieve the record name.
@@ -433,6 +434,15 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         // Ensure the record name is not a reserved keyword.
         ensure!(!Self::is_reserved_keyword(&record_name), "'{record_name}' is a reserved keyword.");
 
+        // Ensure the record does not exceed the maximum number of entries.
+        // Note: The record owner is counted as an entry.
+        let num_entries = record.entries().len().saturating_add(1);
+        ensure!(
+            num_entries <= N::MAX_RECORD_ENTRIES,
+            "Record '{record_name}' exceeds the maximum number of entries ({num_entries} > {}).",
+            N::MAX_RECORD_ENTRIES
+        );
+
         // Ensure all record entries are well-formed.
         // Note: This design ensures cyclic references are not possible.
         for (identifier, entry_type) in record.entries() {
@@ -673,7 +683,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Typ
 mod tests {
     use super::*;
     use console::{
-        network::MainnetV0,
+        network::{MainnetV0, MockNetwork},
         program::{Locator, ValueType},
     };
 
@@ -740,6 +750,53 @@ record foo:
         Ok(())
     }
 
+    #[test]
+    fn test_program_record_max_entries() -> Result<()> {
+        // Generates a program with a record of the given number of entries, including the owner.
+        let program_string = |num_entries: usize| {
+            let mut string = "program unknown.aleo; record foo: owner as address.private;".to_string();
+            for i in 1..num_entries {
+                string.push_str(&format!(" entry_{i} as field.private;"));
+            }
+            string
+        };
+
+        // Ensure a record at the maximum number of entries is added.
+        let program = Program::<CurrentNetwork>::from_str(&program_string(CurrentNetwork::MAX_RECORD_ENTRIES))?;
+        let record = program.get_record(&Identifier::from_str("foo")?)?;
+        assert_eq!(record.entries().len() + 1, CurrentNetwork::MAX_RECORD_ENTRIES);
+
+        // Ensure a record above the maximum number of entries is rejected when its record type is parsed.
+        assert!(Program::<CurrentNetwork>::from_str(&program_string(CurrentNetwork::MAX_RECORD_ENTRIES + 1)).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_record_max_entries_below_data_limit() -> Result<()> {
+        // Note: The mock network lowers the maximum number of record entries below the bound enforced by the parser.
+        // Generates a record of the given number of entries, including the owner.
+        let record_type = |num_entries: usize| {
+            let mut string = "record foo: owner as address.private;".to_string();
+            for i in 1..num_entries {
+                string.push_str(&format!(" entry_{i} as field.private;"));
+            }
+            RecordType::<MockNetwork>::from_str(&string)
+        };
+
+        // Ensure a record at the maximum number of entries is added.
+        let mut program = Program::<MockNetwork>::new(ProgramID::from_str("unknown.aleo")?)?;
+        program.add_record(record_type(MockNetwork::MAX_RECORD_ENTRIES)?)?;
+        assert!(program.contains_record(&Identifier::from_str("foo")?));
+
+        // Ensure a record above the maximum number of entries parses, but is rejected when it is added.
+        let mut program = Program::<MockNetwork>::new(ProgramID::from_str("unknown.aleo")?)?;
+        assert!(program.add_record(record_type(MockNetwork::MAX_RECORD_ENTRIES + 1)?).is_err());
+        assert!(!program.contains_record(&Identifier::from_str("foo")?));
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_function() -> Result<()> {
         // Create a new function.